@@ -1,9 +1,415 @@
+use std::fmt::Write as _;
+use std::path::Path;
+use std::process::Command;
+
 fn main() {
-    // Buy a Mac 
+    // Buy a Mac
     println!(r"cargo:rustc-link-search=/opt/homebrew/lib");
 
     println!("cargo:rerun-if-changed=src/year2018/day9_c/day9.c");
     cc::Build::new()
         .file("src/year2018/day9_c/day9.c")
         .compile("day9_c");
+
+    // build info for the timings history (a missing path would rerun the script on every build)
+    for path in [".git/HEAD", ".git/refs/heads"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    let commit = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_string(), |s| s.trim().to_string());
+
+    println!("cargo:rustc-env=AOR_GIT_COMMIT={commit}");
+    println!(
+        "cargo:rustc-env=AOR_PROFILE={}",
+        std::env::var("PROFILE").unwrap_or_default()
+    );
+
+    let rustc = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_string(), |s| s.trim().to_string());
+    println!("cargo:rustc-env=AOR_RUSTC_VERSION={rustc}");
+
+    // the solutions registry, from the src/yearXXXX/dayYY tree
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    let registry = registry(&Path::new(&manifest_dir).join("src"));
+    std::fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
+
+/// A day solution found in the source tree.
+struct Entry {
+    year: u16,
+    day: u8,
+    alt: Option<String>,
+    /// Name of the directory and of the module, like `day9_c`.
+    module: String,
+    title: String,
+    tags: Vec<&'static str>,
+    slow: bool,
+    answers: (String, String),
+    languages: Vec<String>,
+    /// The day has the `Puzzle::new`, `part1` and `part2` public entry points.
+    puzzle: bool,
+    /// The day has a public `validate` function.
+    validate: bool,
+    /// The samples of `samples.toml`: file, and expected answers of part one and two.
+    samples: Vec<(String, Option<String>, Option<String>)>,
+}
+
+/// Return the sorted entries of a directory whose name starts with `prefix`.
+fn entries(dir: &Path, prefix: &str) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Return the types of the answers, from the signature of the `solve` function.
+fn answer_types(source: &str) -> (String, String) {
+    let signature = source
+        .lines()
+        .find_map(|line| line.strip_prefix("pub fn solve(")?.split_once(") -> ("))
+        .map(|(_, signature)| signature)
+        .unwrap_or_default();
+
+    let mut types = vec![String::new()];
+    let mut depth = 0;
+
+    for c in signature.chars() {
+        match c {
+            ')' | '>' | ']' if depth == 0 => break,
+            ',' if depth == 0 => types.push(String::new()),
+            _ => {
+                match c {
+                    '(' | '<' | '[' => depth += 1,
+                    ')' | '>' | ']' => depth -= 1,
+                    _ => (),
+                }
+                types.last_mut().unwrap().push(c);
+            }
+        }
+    }
+
+    let answer = |i: usize| types.get(i).map_or("", |t| t.trim()).to_string();
+    (answer(0), answer(1))
+}
+
+/// Infer the tags from the source code, then apply the `// tags: ` comment:
+/// `grid`, `graph`, `vm`, `z3` or `rayon` adds a tag, `-graph` removes it, and `slow` marks a slow solution.
+fn tags(source: &str) -> (Vec<&'static str>, bool) {
+    const TAGS: [(&str, &str); 5] = [
+        ("grid", "Grid"),
+        ("graph", "Graph"),
+        ("vm", "Vm"),
+        ("z3", "Z3"),
+        ("rayon", "Rayon"),
+    ];
+
+    let mut tags = Vec::new();
+
+    let uses = |patterns: &[&str]| patterns.iter().any(|pattern| source.contains(pattern));
+
+    if uses(&[
+        "Grid<", "Grid::", "GridU<", "GridU::", "Square<", "Square::",
+    ]) {
+        tags.push("Grid");
+    }
+    if uses(&["petgraph", "rustworkx"]) {
+        tags.push("Graph");
+    }
+    if uses(&["intcode::", "assembunny::", "opcode", "Opcode"]) {
+        tags.push("Vm");
+    }
+    if uses(&["z3::"]) {
+        tags.push("Z3");
+    }
+    if uses(&["rayon::"]) {
+        tags.push("Rayon");
+    }
+
+    let mut slow = false;
+
+    for tag in source
+        .lines()
+        .filter_map(|line| line.strip_prefix("// tags: "))
+        .flat_map(|line| line.split(',').map(str::trim))
+    {
+        if tag == "slow" {
+            slow = true;
+            continue;
+        }
+
+        let (remove, name) = tag
+            .strip_prefix('-')
+            .map_or((false, tag), |name| (true, name));
+        let Some(&(_, variant)) = TAGS.iter().find(|(n, _)| *n == name) else {
+            panic!("unknown tag in // tags: {tag}");
+        };
+
+        tags.retain(|&t| t != variant);
+        if !remove {
+            tags.push(variant);
+        }
+    }
+
+    // in the order of the enum
+    tags.sort_by_key(|tag| TAGS.iter().position(|(_, variant)| variant == tag));
+
+    (tags, slow)
+}
+
+/// Return the languages of the solution, from the links of the year README.
+fn languages(readme: &str, day: u8, module: &str) -> Vec<String> {
+    let prefix = format!("[Day {day}: ");
+    let target = format!("]({module}/");
+
+    let mut languages: Vec<String> = Vec::new();
+
+    if let Some(line) = readme.lines().find(|line| line.starts_with(&prefix)) {
+        for link in line.split("[![").skip(1) {
+            if let Some((label, rest)) = link.split_once(']')
+                && rest.contains(&target)
+                && !languages.iter().any(|l| l == label)
+            {
+                languages.push(label.to_string());
+            }
+        }
+    }
+
+    if languages.is_empty() {
+        languages.push("Rust".to_string());
+    }
+
+    languages
+}
+
+/// Return `true` if the solution has a public `Puzzle` whose parts are solved independently,
+/// i.e. an `impl Puzzle` block with the `new(data: &str)`, `part1(&self)` and `part2(&self)` public methods.
+fn has_puzzle(source: &str) -> bool {
+    if !source
+        .lines()
+        .any(|line| line.starts_with("pub struct Puzzle"))
+    {
+        return false;
+    }
+
+    let mut lines = source.lines();
+    let mut methods = [false; 3];
+
+    while lines
+        .any(|line| line.starts_with("impl Puzzle") || line.starts_with("impl<'a> Puzzle<'a>"))
+    {
+        for line in lines.by_ref().take_while(|line| !line.starts_with('}')) {
+            let Some(method) = line.trim_start().strip_prefix("pub ") else {
+                continue;
+            };
+            let method = method.strip_prefix("const ").unwrap_or(method);
+            let Some(method) = method.strip_prefix("fn ") else {
+                continue;
+            };
+            for (found, prefix) in
+                methods
+                    .iter_mut()
+                    .zip(["new(data: &", "part1(&self)", "part2(&self)"])
+            {
+                *found |= method.starts_with(prefix);
+            }
+        }
+    }
+
+    methods.iter().all(|&found| found)
+}
+
+/// Read the `samples.toml` manifest of a day: one table per `<name>.txt` sample file,
+/// with the expected answers `part1` and/or `part2`.
+fn samples(day_dir: &Path) -> Vec<(String, Option<String>, Option<String>)> {
+    let Ok(manifest) = std::fs::read_to_string(day_dir.join("samples.toml")) else {
+        return vec![];
+    };
+    let manifest: toml::Table = manifest
+        .parse()
+        .unwrap_or_else(|err| panic!("{}/samples.toml: {err}", day_dir.display()));
+
+    let answer = |sample: &toml::Value, part: &str| match sample.get(part)? {
+        toml::Value::String(s) => Some(s.clone()),
+        value => Some(value.to_string()),
+    };
+
+    // sample_2 before sample_10
+    let mut names: Vec<_> = manifest.keys().collect();
+    names.sort_by_key(|name| (name.len(), *name));
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let sample = &manifest[name];
+            let file = day_dir.join(format!("{name}.txt"));
+            assert!(file.is_file(), "missing sample {}", file.display());
+
+            let (part1, part2) = (answer(sample, "part1"), answer(sample, "part2"));
+            (part1.is_some() || part2.is_some()).then(|| (file.display().to_string(), part1, part2))
+        })
+        .collect()
+}
+
+fn scan_year(src: &Path, year: u16) -> Vec<Entry> {
+    let year_dir = src.join(format!("year{year}"));
+    let readme = std::fs::read_to_string(year_dir.join("README.md")).unwrap_or_default();
+
+    let mut days = Vec::new();
+
+    for module in entries(&year_dir, "day") {
+        let Ok(source) =
+            std::fs::read_to_string(year_dir.join(&module).join(format!("{module}.rs")))
+        else {
+            continue;
+        };
+
+        let (day, alt) = module[3..]
+            .split_once('_')
+            .map_or((&module[3..], None), |(day, alt)| {
+                (day, Some(alt.to_string()))
+            });
+        let Ok(day) = day.parse() else {
+            continue;
+        };
+
+        let title = source
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("//! [Day {day}: ")))
+            .and_then(|line| line.rsplit_once("](").map(|(title, _)| title.to_string()))
+            .unwrap_or_default();
+
+        let (tags, slow) = tags(&source);
+
+        days.push(Entry {
+            year,
+            day,
+            alt,
+            title,
+            tags,
+            slow,
+            answers: answer_types(&source),
+            languages: languages(&readme, day, &module),
+            puzzle: has_puzzle(&source),
+            validate: source
+                .lines()
+                .any(|line| line.starts_with("pub fn validate(")),
+            samples: samples(&year_dir.join(&module)),
+            module,
+        });
+    }
+
+    days.sort_by_key(|d| (d.day, d.alt.is_some(), d.alt.clone()));
+    days
+}
+
+/// Generate the modules of the solutions and the functions that return their descriptors.
+fn registry(src: &Path) -> String {
+    let mut code = String::new();
+    let mut years = Vec::new();
+
+    for year_dir in entries(src, "year") {
+        let Ok(year) = year_dir[4..].parse::<u16>() else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed=src/{year_dir}");
+
+        let days = scan_year(src, year);
+        years.push(year);
+
+        let _ = writeln!(
+            code,
+            "#[path = {:?}]",
+            src.join(&year_dir).display().to_string()
+        );
+        let _ = writeln!(code, "#[allow(clippy::module_inception)]");
+        let _ = writeln!(code, "mod {year_dir} {{");
+        for d in &days {
+            let _ = writeln!(code, "    pub mod {0} {{ pub mod {0}; }}", d.module);
+        }
+        let _ = writeln!(code, "}}\n");
+
+        for d in days.iter().filter(|d| !d.samples.is_empty()) {
+            let _ = writeln!(
+                code,
+                "const SAMPLES_{}_{}: &[Sample] = &[",
+                year_dir.to_uppercase(),
+                d.module.to_uppercase()
+            );
+            for (file, part1, part2) in &d.samples {
+                let name = Path::new(file).file_stem().unwrap().to_string_lossy();
+                let _ = writeln!(
+                    code,
+                    "    Sample {{ name: {name:?}, data: include_str!({file:?}), part1: {part1:?}, part2: {part2:?} }},"
+                );
+            }
+            let _ = writeln!(code, "];\n");
+        }
+
+        let _ = writeln!(code, "/// The solutions of {year}.");
+        let _ = writeln!(code, "#[must_use]");
+        let _ = writeln!(code, "pub fn {year_dir}() -> Vec<Solution> {{");
+        let _ = writeln!(code, "    vec![");
+        for d in &days {
+            let _ = writeln!(
+                code,
+                "        solution!({year_dir}::{}, {}, {}, {:?}, {:?}, [{}], {}, ({:?}, {:?}), {:?}{}{}{}),",
+                d.module,
+                d.year,
+                d.day,
+                d.alt,
+                d.title,
+                d.tags.join(", "),
+                d.slow,
+                d.answers.0,
+                d.answers.1,
+                d.languages,
+                if d.puzzle { ", parse: Puzzle" } else { "" },
+                if d.validate {
+                    ", validate: validate"
+                } else {
+                    ""
+                },
+                if d.samples.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        ", samples: SAMPLES_{}_{}",
+                        year_dir.to_uppercase(),
+                        d.module.to_uppercase()
+                    )
+                },
+            );
+        }
+        let _ = writeln!(code, "    ]");
+        let _ = writeln!(code, "}}\n");
+    }
+
+    let _ = writeln!(code, "/// The solutions of all the years.");
+    let _ = writeln!(code, "fn registry() -> impl Iterator<Item = Solution> {{");
+    let _ = writeln!(code, "    std::iter::empty()");
+    for year in years {
+        let _ = writeln!(code, "        .chain(year{year}())");
+    }
+    let _ = writeln!(code, "}}");
+
+    code
 }
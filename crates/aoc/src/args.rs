@@ -1,24 +1,224 @@
 use colored::Colorize;
 use std::{
     fmt::Display,
+    str::FromStr,
     time::{Duration, Instant},
 };
 
 use crate::load_input_data;
 
+/// A command-line option, a flag or an option with a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Opt {
+    /// Long name with the leading dashes, like `--verbose`.
+    pub long: &'static str,
+    pub short: Option<char>,
+    /// Name of the value if the option takes one, like `N` for `--jobs=N`.
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+impl Opt {
+    /// A flag, without value.
+    #[must_use]
+    pub const fn flag(long: &'static str, help: &'static str) -> Self {
+        Self {
+            long,
+            short: None,
+            value: None,
+            help,
+        }
+    }
+
+    /// An option with a value, given as `--name=value` or `--name value`.
+    #[must_use]
+    pub const fn with_value(long: &'static str, value: &'static str, help: &'static str) -> Self {
+        Self {
+            long,
+            short: None,
+            value: Some(value),
+            help,
+        }
+    }
+
+    /// Add a short name, like `-v`.
+    #[must_use]
+    pub const fn short(self, short: char) -> Self {
+        Self {
+            short: Some(short),
+            ..self
+        }
+    }
+
+    fn usage(&self) -> String {
+        let short = self
+            .short
+            .map_or_else(|| "    ".to_string(), |c| format!("-{c}, "));
+        let value = self.value.map(|v| format!("={v}")).unwrap_or_default();
+        format!("{short}{}{value}", self.long)
+    }
+}
+
+/// A subcommand, with its own options.
+#[derive(Clone, Copy, Debug)]
+pub struct Command<'a> {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub options: &'a [Opt],
+}
+
+/// The command-line grammar of a program.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Spec<'a> {
+    pub about: &'a str,
+    /// Usage of the positional parameters, like `[INPUT]`.
+    pub params: &'a str,
+    /// Options allowed with all the commands.
+    pub options: &'a [Opt],
+    pub commands: &'a [Command<'a>],
+    /// Command whose options are allowed when no command is given.
+    pub default_command: Option<&'static str>,
+    /// Keep the unknown options as flags instead of failing.
+    pub lenient: bool,
+}
+
+/// The help option, implicit in all specifications.
+const HELP: Opt = Opt::flag("--help", "Print help").short('h');
+
+/// The options of the puzzle solvers.
+const DAY_OPTIONS: [Opt; 2] = [
+    Opt::flag("--verbose", "Use verbose output").short('v'),
+    Opt::flag("--elapsed", "Show duration"),
+];
+
+impl Spec<'_> {
+    fn command(&self, name: &str) -> Option<&Command<'_>> {
+        self.commands.iter().find(|cmd| cmd.name == name)
+    }
+
+    /// Find an option by its long name (`--name`) or its short name (`c`).
+    fn find(&self, command: Option<&str>, long: Option<&str>, short: Option<char>) -> Option<Opt> {
+        let command_options = command
+            .or(self.default_command)
+            .and_then(|name| self.command(name))
+            .map(|cmd| cmd.options)
+            .unwrap_or_default();
+
+        std::iter::once(&HELP)
+            .chain(self.options)
+            .chain(command_options)
+            .find(|opt| {
+                long.is_some_and(|long| opt.long == long)
+                    || short.is_some_and(|c| opt.short == Some(c))
+            })
+            .copied()
+    }
+
+    /// Generate the help text of the program.
+    #[must_use]
+    pub fn help(&self, name: &str) -> String {
+        let mut sections: Vec<(String, Vec<(String, &str)>)> = Vec::new();
+
+        if !self.commands.is_empty() {
+            sections.push((
+                "Commands:".to_string(),
+                self.commands
+                    .iter()
+                    .map(|cmd| (cmd.name.to_string(), cmd.help))
+                    .collect(),
+            ));
+        }
+
+        sections.push((
+            "Options:".to_string(),
+            std::iter::once(&HELP)
+                .chain(self.options)
+                .map(|opt| (opt.usage(), opt.help))
+                .collect(),
+        ));
+
+        for cmd in self.commands.iter().filter(|cmd| !cmd.options.is_empty()) {
+            sections.push((
+                format!("Options of {}:", cmd.name),
+                cmd.options
+                    .iter()
+                    .map(|opt| (opt.usage(), opt.help))
+                    .collect(),
+            ));
+        }
+
+        let width = sections
+            .iter()
+            .flat_map(|(_, lines)| lines.iter().map(|(usage, _)| usage.len()))
+            .max()
+            .unwrap_or(0);
+
+        let command = if self.commands.is_empty() {
+            ""
+        } else {
+            " [COMMAND]"
+        };
+
+        let mut help = format!(
+            "{}\n\n{} {} {}\n",
+            self.about,
+            "Usage:".green().bold(),
+            name.cyan().bold(),
+            format!("[OPTIONS]{command} {}", self.params)
+                .trim_end()
+                .cyan()
+        );
+
+        for (title, lines) in sections {
+            help.push('\n');
+            help.push_str(&format!("{}\n", title.green().bold()));
+            for (usage, text) in lines {
+                help.push_str(&format!(
+                    "  {}  {text}\n",
+                    format!("{usage:width$}").cyan().bold()
+                ));
+            }
+        }
+
+        help
+    }
+}
+
+/// An option found on the command line.
+#[derive(Debug)]
+struct Matched {
+    long: String,
+    short: Option<char>,
+    value: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct Args {
-    input: String,        // puzzle input
-    verbose: bool,        // activate the verbose flag
-    options: Vec<String>, // copy of Args() (with a leading -)
-    params: Vec<String>,  // copy of Args() (without the leading -)
-    elapsed: bool,        // flag to show elapsed time
+    input: String,           // puzzle input
+    verbose: bool,           // activate the verbose flag
+    command: Option<String>, // the subcommand, if any
+    options: Vec<Matched>,   // the options found on the command line
+    params: Vec<String>,     // the positional parameters
+    elapsed: bool,           // flag to show elapsed time
 }
 
 impl Args {
     #[must_use]
     pub fn parse_args() -> Self {
-        let mut args = Self::parse_args_raw();
+        Self::parse_args_with(&[])
+    }
+
+    /// Parse the command line of a puzzle solver that accepts extra options, and load the input.
+    #[must_use]
+    pub fn parse_args_with(options: &[Opt]) -> Self {
+        let options: Vec<Opt> = DAY_OPTIONS.iter().chain(options).copied().collect();
+
+        let mut args = Self::parse_spec(&Spec {
+            about: "Advent of Code's puzzle solver",
+            params: "[INPUT]",
+            options: &options,
+            ..Spec::default()
+        });
 
         let path = args.params.first().map_or("input.txt", |f| f.as_str());
 
@@ -38,35 +238,201 @@ impl Args {
         self.verbose
     }
 
+    /// Parse the command line without specification: all the options are accepted as flags.
     #[must_use]
     pub fn parse_args_raw() -> Self {
-        let help = std::env::args().any(|a| a == "--help" || a == "-h");
-        if help {
-            usage();
+        Self::parse_spec(&Spec {
+            about: "Advent of Code's puzzle solver",
+            params: "[INPUT]",
+            options: &DAY_OPTIONS,
+            lenient: true,
+            ..Spec::default()
+        })
+    }
+
+    /// Parse the command line according to the specification.
+    ///
+    /// Print the help and exit with `-h`/`--help`, print the error and exit on invalid arguments.
+    #[must_use]
+    pub fn parse_spec(spec: &Spec) -> Self {
+        match Self::try_parse_from(spec, std::env::args().skip(1)) {
+            Ok(args) => {
+                if args.has_option("--help") {
+                    print!("{}", spec.help(&program_name()));
+                    std::process::exit(0);
+                }
+                args
+            }
+            Err(err) => {
+                eprintln!("{} {err}", "error:".red().bold());
+                eprintln!();
+                eprintln!("For more information, try '{}'.", "--help".cyan().bold());
+                std::process::exit(2);
+            }
         }
+    }
 
-        let verbose = std::env::args().any(|a| a == "--verbose" || a == "-v");
-        let elapsed = std::env::args().any(|a| a == "--elapsed");
+    /// Parse the arguments, without the program name.
+    ///
+    /// Options are `--name`, `--name=value`, `--name value`, `-c`, `-cVALUE` and `-c VALUE`,
+    /// and short flags can be combined, like `-va`. Everything after `--` is a parameter.
+    /// The subcommand, if any, is the first parameter.
+    ///
+    /// # Errors
+    /// Returns a message if an option is unknown, misses its value or has an unexpected one.
+    pub fn try_parse_from<I, S>(spec: &Spec, args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut command: Option<String> = None;
+        let mut options = Vec::new();
+        let mut params = Vec::new();
+        let mut only_params = false;
 
-        let options = std::env::args().filter(|a| a.starts_with('-')).collect();
-        let params: Vec<String> = std::env::args()
-            .skip(1)
-            .filter(|a| !a.starts_with('-'))
-            .collect();
+        let mut args = args.into_iter().map(Into::into);
 
-        Self {
+        while let Some(arg) = args.next() {
+            if only_params || arg == "-" || !arg.starts_with('-') {
+                if command.is_none() && params.is_empty() && spec.command(&arg).is_some() {
+                    command = Some(arg);
+                } else {
+                    params.push(arg);
+                }
+            } else if arg == "--" {
+                only_params = true;
+            } else if arg.starts_with("--") {
+                let (long, value) = arg
+                    .split_once('=')
+                    .map_or((arg.as_str(), None), |(long, value)| {
+                        (long, Some(value.to_string()))
+                    });
+
+                match spec.find(command.as_deref(), Some(long), None) {
+                    Some(opt) => {
+                        let value = match (opt.value, value) {
+                            (Some(_), Some(value)) => Some(value),
+                            (Some(name), None) => Some(args.next().ok_or_else(|| {
+                                format!("option '{long}' requires a value {name}")
+                            })?),
+                            (None, Some(_)) => {
+                                return Err(format!("option '{long}' does not take a value"));
+                            }
+                            (None, None) => None,
+                        };
+                        options.push(Matched {
+                            long: opt.long.to_string(),
+                            short: opt.short,
+                            value,
+                        });
+                    }
+                    None if spec.lenient => options.push(Matched {
+                        long: long.to_string(),
+                        short: None,
+                        value,
+                    }),
+                    None => return Err(format!("unknown option '{long}'")),
+                }
+            } else {
+                let flags = &arg[1..];
+
+                for (i, c) in flags.char_indices() {
+                    match spec.find(command.as_deref(), None, Some(c)) {
+                        Some(opt) if opt.value.is_some() => {
+                            let rest = &flags[i + c.len_utf8()..];
+                            let value = if rest.is_empty() {
+                                args.next().ok_or_else(|| {
+                                    format!("option '-{c}' requires a value {}", opt.value.unwrap())
+                                })?
+                            } else {
+                                rest.to_string()
+                            };
+                            options.push(Matched {
+                                long: opt.long.to_string(),
+                                short: opt.short,
+                                value: Some(value),
+                            });
+                            break;
+                        }
+                        Some(opt) => options.push(Matched {
+                            long: opt.long.to_string(),
+                            short: opt.short,
+                            value: None,
+                        }),
+                        None if spec.lenient => {
+                            options.push(Matched {
+                                long: arg.clone(),
+                                short: None,
+                                value: None,
+                            });
+                            break;
+                        }
+                        None => return Err(format!("unknown option '-{c}'")),
+                    }
+                }
+            }
+        }
+
+        let mut args = Self {
             input: String::new(),
-            verbose,
+            verbose: false,
+            command: command.or_else(|| spec.default_command.map(str::to_string)),
             options,
             params,
-            elapsed,
-        }
+            elapsed: false,
+        };
+
+        args.verbose = args.has_option("--verbose");
+        args.elapsed = args.has_option("--elapsed");
+
+        Ok(args)
+    }
+
+    fn find(&self, option: &str) -> Option<&Matched> {
+        let short = option
+            .strip_prefix('-')
+            .filter(|c| c.chars().count() == 1)
+            .and_then(|c| c.chars().next());
+
+        self.options
+            .iter()
+            .find(|m| m.long == option || (short.is_some() && m.short == short))
     }
 
+    /// Return `true` if the option is on the command line, given by its long or its short name.
     #[must_use]
     pub fn has_option(&self, option: &str) -> bool {
-        self.options.iter().filter(|s| *s == option).count() != 0
-        //self.options.contains(option)
+        self.find(option).is_some()
+    }
+
+    /// Return the value of an option given as `--name=value` or `--name value`.
+    #[must_use]
+    pub fn option_value(&self, option: &str) -> Option<&str> {
+        self.find(option).and_then(|m| m.value.as_deref())
+    }
+
+    /// Return the value of an option converted to `T`, or `None` if the option is absent.
+    ///
+    /// # Errors
+    /// Returns a message if the value cannot be converted.
+    pub fn value_of<T>(&self, option: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.option_value(option)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|err| format!("invalid value '{value}' for '{option}': {err}"))
+            })
+            .transpose()
+    }
+
+    /// The subcommand, or the default one if none is given.
+    #[must_use]
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
     }
 
     #[must_use]
@@ -75,33 +441,12 @@ impl Args {
     }
 }
 
-/// Show command-line usage.
-fn usage() {
-    let name = std::env::current_exe()
-        .unwrap_or_else(|_| "<program>".into())
-        .file_name()
-        .unwrap()
-        .to_os_string();
-    let name = name.to_str().unwrap();
-
-    println!("Advent of Code's puzzle solver");
-    println!();
-    println!(
-        "{} {} {}",
-        "Usage:".green().bold(),
-        name.cyan().bold(),
-        "[OPTIONS] [INPUT]".cyan()
-    );
-    println!();
-    println!("{}", "Options:".green().bold());
-    println!(
-        "  {}, {}          Use verbose output",
-        "-v".cyan().bold(),
-        "--verbose".cyan().bold()
-    );
-    println!("      {}          Show duration", "--elapsed".cyan().bold());
-
-    std::process::exit(0); //std::process::ExitCode::SUCCESS);
+/// The name of the running program.
+fn program_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.file_name()?.to_str().map(str::to_string))
+        .unwrap_or_else(|| "<program>".to_string())
 }
 
 impl Args {
@@ -0,0 +1,191 @@
+//! `Coord3` class represents a point, a vector or a direction in 3D.
+//!
+//! Advent-of-Rust 2024
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct Coord3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// The six face neighbors.
+const FACES: [Coord3; 6] = [
+    Coord3::new(1, 0, 0),
+    Coord3::new(-1, 0, 0),
+    Coord3::new(0, 1, 0),
+    Coord3::new(0, -1, 0),
+    Coord3::new(0, 0, 1),
+    Coord3::new(0, 0, -1),
+];
+
+impl Coord3 {
+    pub const ZERO: Self = Self { x: 0, y: 0, z: 0 };
+
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn manhattan_distance(self, rhs: Self) -> i32 {
+        (self.x - rhs.x).abs() + (self.y - rhs.y).abs() + (self.z - rhs.z).abs()
+    }
+
+    /// Rotate a quarter turn around the x axis, from y to z.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_x(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.z,
+            z: self.y,
+        }
+    }
+
+    /// Rotate a quarter turn around the y axis, from z to x.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_y(self) -> Self {
+        Self {
+            x: self.z,
+            y: self.y,
+            z: -self.x,
+        }
+    }
+
+    /// Rotate a quarter turn around the z axis, from x to y.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_z(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    /// Returns the 24 orientations of the vector, i.e. its images by the rotations of the cube.
+    #[must_use]
+    pub fn orientations(self) -> [Self; 24] {
+        let mut orientations = [Self::ZERO; 24];
+
+        // each of the six faces up, then four quarter turns around the up axis
+        let mut up = self;
+        for face in 0..6 {
+            let mut v = up;
+            for turn in 0..4 {
+                orientations[face * 4 + turn] = v;
+                v = v.rotate_z();
+            }
+            up = if face % 2 == 0 {
+                up.rotate_x()
+            } else {
+                up.rotate_y()
+            };
+        }
+
+        orientations
+    }
+
+    /// Returns an iterator over the six face neighbors.
+    pub fn iter_faces(self) -> impl Iterator<Item = Self> {
+        FACES.iter().map(move |&d| self + d)
+    }
+
+    /// Returns an iterator over the 26 neighbors, sharing a face, an edge or a corner.
+    pub fn iter_neighbors(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Self::new(x, y, z))))
+            .filter(|&d| d != Self::ZERO)
+            .map(move |d| self + d)
+    }
+}
+
+impl Add for Coord3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl AddAssign for Coord3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Sub for Coord3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl SubAssign for Coord3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl Mul<i32> for Coord3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Mul<Coord3> for i32 {
+    type Output = Coord3;
+    #[inline]
+    fn mul(self, other: Coord3) -> Coord3 {
+        other * self
+    }
+}
+
+impl MulAssign<i32> for Coord3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl From<(i32, i32, i32)> for Coord3 {
+    #[inline]
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl std::fmt::Display for Coord3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,178 @@
+//! `CoordN` class represents a point, a vector or a direction in any dimension.
+//!
+//! Advent-of-Rust 2024
+
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{Coord, Coord3};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct CoordN<const D: usize>(pub [i32; D]);
+
+impl<const D: usize> CoordN<D> {
+    pub const ZERO: Self = Self([0; D]);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(coords: [i32; D]) -> Self {
+        Self(coords)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn manhattan_distance(self, rhs: Self) -> i32 {
+        self.0.iter().zip(rhs.0).map(|(a, b)| (a - b).abs()).sum()
+    }
+
+    /// Rotate a quarter turn in the plane of the axes `from` and `to`, from `from` to `to`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate(mut self, from: usize, to: usize) -> Self {
+        let a = self.0[from];
+        self.0[from] = -self.0[to];
+        self.0[to] = a;
+        self
+    }
+
+    /// Returns an iterator over the `2 * D` neighbors along the axes.
+    pub fn iter_faces(self) -> impl Iterator<Item = Self> {
+        (0..D).flat_map(move |axis| {
+            [-1, 1].map(|d| {
+                let mut c = self;
+                c.0[axis] += d;
+                c
+            })
+        })
+    }
+
+    /// Returns an iterator over the `3^D - 1` neighbors, including the diagonal ones.
+    pub fn iter_neighbors(self) -> impl Iterator<Item = Self> {
+        let count = 3_usize.pow(u32::try_from(D).unwrap_or_default());
+
+        (0..count).filter_map(move |mut n| {
+            let mut c = self;
+            for v in &mut c.0 {
+                *v += i32::try_from(n % 3).unwrap_or_default() - 1;
+                n /= 3;
+            }
+            (c != self).then_some(c)
+        })
+    }
+}
+
+impl<const D: usize> Default for CoordN<D> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const D: usize> Index<usize> for CoordN<D> {
+    type Output = i32;
+    #[inline]
+    fn index(&self, axis: usize) -> &i32 {
+        &self.0[axis]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for CoordN<D> {
+    #[inline]
+    fn index_mut(&mut self, axis: usize) -> &mut i32 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const D: usize> Add for CoordN<D> {
+    type Output = Self;
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<const D: usize> AddAssign for CoordN<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<const D: usize> Sub for CoordN<D> {
+    type Output = Self;
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const D: usize> SubAssign for CoordN<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const D: usize> Mul<i32> for CoordN<D> {
+    type Output = Self;
+    #[inline]
+    fn mul(mut self, rhs: i32) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<const D: usize> Mul<CoordN<D>> for i32 {
+    type Output = CoordN<D>;
+    #[inline]
+    fn mul(self, other: CoordN<D>) -> CoordN<D> {
+        other * self
+    }
+}
+
+impl<const D: usize> MulAssign<i32> for CoordN<D> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        for a in &mut self.0 {
+            *a *= rhs;
+        }
+    }
+}
+
+impl<const D: usize> From<[i32; D]> for CoordN<D> {
+    #[inline]
+    fn from(coords: [i32; D]) -> Self {
+        Self(coords)
+    }
+}
+
+impl From<Coord> for CoordN<2> {
+    #[inline]
+    fn from(c: Coord) -> Self {
+        Self([c.x, c.y])
+    }
+}
+
+impl From<Coord3> for CoordN<3> {
+    #[inline]
+    fn from(c: Coord3) -> Self {
+        Self([c.x, c.y, c.z])
+    }
+}
+
+impl<const D: usize> std::fmt::Display for CoordN<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{v}")?;
+        }
+        write!(f, ")")
+    }
+}
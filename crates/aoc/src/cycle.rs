@@ -0,0 +1,194 @@
+//! Cycle detection in the sequence of states of a simulation, to skip ahead to a far step.
+//!
+//! [`floyd`] and [`brent`] need only a few states at a time, but compare whole states.
+//! [`find`], [`nth`] and [`extrapolate`] remember a key of each state, like its [`fingerprint`],
+//! and step the state in place, so large states are never cloned.
+//!
+//! ```
+//! use aoc::cycle;
+//!
+//! // 0, 1, 2, 5, 4, 6, 4, 6, ...
+//! let step = |&x: &u32| (x * x + 1) % 11;
+//! let cycle = cycle::brent(0, step);
+//! assert_eq!((cycle.start, cycle.length), (4, 2));
+//!
+//! let state = cycle::nth(0, |x| *x = step(x), |&x| x, 1_000_000_000);
+//! assert_eq!(state, 4);
+//! ```
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// A cycle of the sequence of states: the state at step `start + length` is the state at step `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// First step of the cycle.
+    pub start: usize,
+    /// Number of steps of the cycle.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    #[must_use]
+    pub const fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Find the cycle with Floyd's tortoise and hare.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Find the cycle with Brent's algorithm, that calls `step` less often than Floyd's.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// A fingerprint of a state, to remember it without cloning it.
+///
+/// Two different states may have the same fingerprint, though it is very unlikely.
+#[must_use]
+pub fn fingerprint<T: Hash + ?Sized>(state: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Step `state` until its key is seen again or `limit` steps are done.
+fn detect<S, K: Eq + Hash>(
+    state: &mut S,
+    step: &mut impl FnMut(&mut S),
+    key: &mut impl FnMut(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+
+    for i in 0..limit {
+        match seen.entry(key(state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Some(Cycle {
+                    start,
+                    length: i - start,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+        step(state);
+    }
+
+    None
+}
+
+/// Find the cycle by remembering the key of each state.
+///
+/// On return, `state` is at step `start + length`, i.e. the first state of the cycle.
+pub fn find<S, K: Eq + Hash>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    detect(state, &mut step, &mut key, usize::MAX)
+        .unwrap_or_else(|| unreachable!("no cycle within usize::MAX steps"))
+}
+
+/// The state at step `n`, skipping the whole cycles once it is found.
+pub fn nth<S, K: Eq + Hash>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    n: usize,
+) -> S {
+    if let Some(cycle) = detect(&mut state, &mut step, &mut key, n) {
+        for _ in 0..(n - cycle.start - cycle.length) % cycle.length {
+            step(&mut state);
+        }
+    }
+    state
+}
+
+/// The value of the state at step `n`, when the value increases by the same amount over each cycle,
+/// e.g. the height of a tower of falling rocks.
+///
+/// # Panics
+/// If the value overflows.
+pub fn extrapolate<S, K: Eq + Hash>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> i64,
+    n: usize,
+) -> i64 {
+    let mut values = Vec::new();
+    let mut key = |state: &S| {
+        values.push(value(state));
+        key(state)
+    };
+
+    let Some(cycle) = detect(&mut state, &mut step, &mut key, n) else {
+        return value(&state);
+    };
+
+    let delta = values[cycle.start + cycle.length] - values[cycle.start];
+    let cycles = i64::try_from((n - cycle.start) / cycle.length).unwrap();
+    values[cycle.reduce(n)] + cycles * delta
+}
@@ -0,0 +1,230 @@
+//! `DynGrid` class, a grid that grows to include every written cell.
+//!
+//! Advent-of-Rust 2024
+
+use std::ops::{Index, IndexMut};
+
+use crate::grid::{DIRECTIONS, NEIGHBORS, render};
+use crate::{Coord, Direction, Grid};
+
+#[derive(Debug, Clone)]
+pub struct DynGrid<T> {
+    origin: Coord, // coordinates of data[0]
+    size: Coord,
+    data: Vec<T>,
+    default: T,                     // value of the cells never written
+    bounds: Option<(Coord, Coord)>, // bounding box of the written cells
+}
+
+impl<T: Clone> DynGrid<T> {
+    /// Construct a new, empty `DynGrid<T>`, whose cells are `default` until written.
+    #[must_use]
+    pub const fn new(default: T) -> Self {
+        Self {
+            origin: Coord::ZERO,
+            size: Coord::ZERO,
+            data: Vec::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Return the bounding box of the written cells, as its top left and bottom right corners.
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    /// Return the width of the bounding box.
+    #[must_use]
+    pub fn width(&self) -> i32 {
+        self.bounds.map_or(0, |(min, max)| max.x - min.x + 1)
+    }
+
+    /// Return the height of the bounding box.
+    #[must_use]
+    pub fn height(&self) -> i32 {
+        self.bounds.map_or(0, |(min, max)| max.y - min.y + 1)
+    }
+
+    /// Index of a cell in the storage, if it is allocated.
+    #[inline]
+    fn offset(&self, pos: Coord) -> Option<usize> {
+        let p = pos - self.origin;
+        if 0 <= p.x && p.x < self.size.x && 0 <= p.y && p.y < self.size.y {
+            usize::try_from(p.y * self.size.x + p.x).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Grow the storage to include `pos`, with some room to grow further in the same direction.
+    fn reserve(&mut self, pos: Coord) {
+        if self.offset(pos).is_some() {
+            return;
+        }
+
+        let (mut min, mut max) = if self.data.is_empty() {
+            (pos, pos)
+        } else {
+            (self.origin, self.origin + self.size - Coord::new(1, 1))
+        };
+        let margin = Coord::new(self.size.x / 2 + 1, self.size.y / 2 + 1);
+
+        if pos.x < min.x {
+            min.x = pos.x - margin.x;
+        }
+        if pos.y < min.y {
+            min.y = pos.y - margin.y;
+        }
+        if pos.x > max.x {
+            max.x = pos.x + margin.x;
+        }
+        if pos.y > max.y {
+            max.y = pos.y + margin.y;
+        }
+
+        let mut grown = Self {
+            origin: min,
+            size: max - min + Coord::new(1, 1),
+            data: Vec::new(),
+            default: self.default.clone(),
+            bounds: self.bounds,
+        };
+        let len = usize::try_from(grown.size.x * grown.size.y).unwrap_or_default();
+        grown.data.resize(len, self.default.clone());
+
+        for (pos, c) in self.iter_allocated() {
+            if let Some(idx) = grown.offset(pos) {
+                grown.data[idx] = c.clone();
+            }
+        }
+
+        *self = grown;
+    }
+
+    /// Iterate over all allocated cells, written or not.
+    fn iter_allocated(&self) -> impl Iterator<Item = (Coord, &T)> {
+        (0..).zip(self.data.iter()).map(move |(i, c)| {
+            let x = i % self.size.x;
+            let y = i / self.size.x;
+            (self.origin + Coord::new(x, y), c)
+        })
+    }
+
+    /// Iterate over all cells of the bounding box.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let (min, max) = self
+            .bounds
+            .unwrap_or((Coord::new(0, 0), Coord::new(-1, -1)));
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Coord::new(x, y)))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Returns an iterator over the all four directions.
+    pub fn iter_directions(&self, xy: Coord) -> impl Iterator<Item = (Direction, Coord)> + '_ {
+        DIRECTIONS.iter().map(move |&(d, dxy)| (d, xy + dxy))
+    }
+
+    /// Returns an iterator over the all eight neighbors.
+    pub fn iter_neighbors(&self, xy: Coord) -> impl Iterator<Item = Coord> + '_ {
+        NEIGHBORS.iter().map(move |&dxy| xy + dxy)
+    }
+}
+
+impl<T: Clone + Default> Default for DynGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone + Default + PartialEq> From<&Grid<T>> for DynGrid<T> {
+    /// Copy the cells of a grid that are not its exterior, which becomes the default value.
+    fn from(grid: &Grid<T>) -> Self {
+        let mut dyngrid = Self::new(grid.exterior().clone());
+        for (pos, c) in grid {
+            if *c != dyngrid.default {
+                dyngrid[pos] = c.clone();
+            }
+        }
+        dyngrid
+    }
+}
+
+//
+// implement []
+//
+
+impl<T: Clone> Index<Coord> for DynGrid<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, pos: Coord) -> &Self::Output {
+        self.offset(pos)
+            .map_or(&self.default, |idx| &self.data[idx])
+    }
+}
+
+impl<T: Clone> IndexMut<Coord> for DynGrid<T> {
+    /// Return the cell, growing the grid if needed.
+    #[inline]
+    fn index_mut(&mut self, pos: Coord) -> &mut Self::Output {
+        self.reserve(pos);
+
+        self.bounds = Some(self.bounds.map_or((pos, pos), |(min, max)| {
+            (Coord::new(min.x.min(pos.x), min.y.min(pos.y)), max.max(pos))
+        }));
+
+        let idx = self.offset(pos).unwrap_or_default();
+        &mut self.data[idx]
+    }
+}
+
+//
+// char and u8 specializations
+//
+
+impl From<&str> for DynGrid<char> {
+    /// Read a grid from a puzzle input, writing the cells that are not `.`.
+    fn from(input: &str) -> Self {
+        let mut grid = Self::new('.');
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, c) in (0..).zip(line.chars()) {
+                if c != '.' {
+                    grid[Coord::new(x, y)] = c;
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl From<&str> for DynGrid<u8> {
+    /// Read a grid from a puzzle input, writing the cells that are not `.`.
+    fn from(input: &str) -> Self {
+        let mut grid = Self::new(b'.');
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, &c) in (0..).zip(line.as_bytes()) {
+                if c != b'.' {
+                    grid[Coord::new(x, y)] = c;
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl std::fmt::Display for DynGrid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.bounds
+            .map_or(Ok(()), |bounds| render(f, bounds, |pos| self[pos]))
+    }
+}
+
+impl std::fmt::Display for DynGrid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.bounds
+            .map_or(Ok(()), |bounds| render(f, bounds, |pos| self[pos] as char))
+    }
+}
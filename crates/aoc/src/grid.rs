@@ -7,8 +7,9 @@ use std::ops::{Index, IndexMut};
 
 use crate::Coord;
 use crate::Direction;
+use crate::GridLike;
 
-const NEIGHBORS: [Coord; 8] = [
+pub const NEIGHBORS: [Coord; 8] = [
     Coord::new(0, -1),  // N
     Coord::new(1, -1),  // NE
     Coord::new(1, 0),   // E
@@ -19,6 +20,28 @@ const NEIGHBORS: [Coord; 8] = [
     Coord::new(-1, -1), // NW
 ];
 
+pub const DIRECTIONS: [(Direction, Coord); 4] = [
+    (Direction::North, Coord::NORTH),
+    (Direction::East, Coord::EAST),
+    (Direction::South, Coord::SOUTH),
+    (Direction::West, Coord::WEST),
+];
+
+/// Write the rows of cells from `min` to `max`, both included.
+pub fn render(
+    f: &mut std::fmt::Formatter<'_>,
+    (min, max): (Coord, Coord),
+    cell: impl Fn(Coord) -> char,
+) -> std::fmt::Result {
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            write!(f, "{}", cell(Coord::new(x, y)))?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
     size: Coord,
@@ -64,6 +87,13 @@ impl<T: Clone + Default> Grid<T> {
         }
     }
 
+    /// Return the value returned by Index if pos is outside the grid limits
+    #[inline]
+    #[must_use]
+    pub const fn exterior(&self) -> &T {
+        &self.exterior
+    }
+
     /// Set value returned by Index if pos is outside the grid limits
     #[inline]
     pub fn set_exterior(&mut self, exterior: T) {
@@ -161,52 +191,24 @@ impl<T: Clone + Default> Default for Grid<T> {
 }
 
 //
-// implement Rotation (should be a trait...)
+// implement Rotation, with GridLike
 //
 
 impl<T: Copy + Default> Grid<T> {
     pub fn rotate_clockwise(&self) -> Self {
-        let mut rotated = Self::with_size(self.height(), self.width(), self.exterior, self.dummy);
-
-        for x in 0..self.width() {
-            for y in 0..self.height() {
-                rotated[(self.height() - y - 1, x)] = self[(x, y)];
-            }
-        }
-        rotated
+        GridLike::rotate_clockwise(self)
     }
 
     pub fn rotate_counterclockwise(&self) -> Self {
-        let mut rotated = Self::with_size(self.height(), self.width(), self.exterior, self.dummy);
-
-        for x in 0..self.width() {
-            for y in 0..self.height() {
-                rotated[(y, self.width() - 1 - x)] = self[(x, y)];
-            }
-        }
-        rotated
+        GridLike::rotate_counterclockwise(self)
     }
 
     pub fn flip_vertical(&self) -> Self {
-        let mut flipped = Self::with_size(self.width(), self.height(), self.exterior, self.dummy);
-
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                flipped[(x, y)] = self[(self.width() - 1 - x, y)];
-            }
-        }
-        flipped
+        GridLike::flip_vertical(self)
     }
 
     pub fn flip_horizontal(&self) -> Self {
-        let mut flipped = Self::with_size(self.width(), self.height(), self.exterior, self.dummy);
-
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                flipped[(x, y)] = self[(x, self.height() - 1 - y)];
-            }
-        }
-        flipped
+        GridLike::flip_horizontal(self)
     }
 }
 
@@ -400,16 +402,7 @@ impl Grid<char> {
 
 impl std::fmt::Display for Grid<char> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.size.y {
-            for x in 0..self.size.x {
-                let idx = self.size.x * y + x;
-                let idx: usize = idx.try_into().unwrap();
-
-                write!(f, "{}", self.data[idx])?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.fmt_cells(f, |&c| c)
     }
 }
 
@@ -453,15 +446,6 @@ impl Grid<u8> {
 
 impl std::fmt::Display for Grid<u8> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                let idx = self.size.x * y + x;
-                let idx: usize = idx.try_into().unwrap();
-
-                write!(f, "{}", self.data[idx] as char)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.fmt_cells(f, |&c| c as char)
     }
 }
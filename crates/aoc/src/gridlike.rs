@@ -0,0 +1,425 @@
+//! `GridLike` trait, implemented by the rectangular grids `Grid`, `GridU` and `Square`.
+//!
+//! The required methods give the dimensions, the cells by their `(x, y)` offsets and the conversions
+//! from and to the positions of the grid. The neighbourhoods, transforms, sub-grids, rendering,
+//! flood fill and connected components are written once on top of them.
+//!
+//! Advent-of-Rust 2024
+
+use crate::{Coord, Grid, GridU, Square, UnionFind};
+
+/// A rectangular grid of cells, addressed by their column and row.
+pub trait GridLike {
+    /// The type of the cells.
+    type Cell: Clone;
+
+    /// The type of the positions, like `Coord` or `(usize, usize)`.
+    type Pos: Copy;
+
+    /// Returns the number of columns.
+    fn width(&self) -> usize;
+
+    /// Returns the number of rows.
+    fn height(&self) -> usize;
+
+    /// Returns the cell at column `x` and row `y`, that must be in the grid.
+    fn cell(&self, x: usize, y: usize) -> &Self::Cell;
+
+    /// Returns the mutable cell at column `x` and row `y`, that must be in the grid.
+    fn cell_mut(&mut self, x: usize, y: usize) -> &mut Self::Cell;
+
+    /// Returns a grid of the same kind with the given dimensions and default cells.
+    #[must_use]
+    fn new_like(&self, width: usize, height: usize) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the position of column `x` and row `y`.
+    fn pos(x: usize, y: usize) -> Self::Pos;
+
+    /// Returns the column and the row of a position, if it is in the grid.
+    fn xy(&self, pos: Self::Pos) -> Option<(usize, usize)>;
+
+    /// Returns the cell at a position, if it is in the grid.
+    fn get(&self, pos: Self::Pos) -> Option<&Self::Cell> {
+        let (x, y) = self.xy(pos)?;
+        Some(self.cell(x, y))
+    }
+
+    /// Returns the mutable cell at a position, if it is in the grid.
+    fn get_mut(&mut self, pos: Self::Pos) -> Option<&mut Self::Cell> {
+        let (x, y) = self.xy(pos)?;
+        Some(self.cell_mut(x, y))
+    }
+
+    /// Iterate over the positions and the cells, row by row.
+    fn cells(&self) -> impl Iterator<Item = (Self::Pos, &Self::Cell)> {
+        (0..self.height())
+            .flat_map(move |y| (0..self.width()).map(move |x| (Self::pos(x, y), self.cell(x, y))))
+    }
+
+    /// Iterate over the four neighbors of a position, within the limits of the grid.
+    fn neighbors4(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        let (width, height) = (self.width(), self.height());
+        self.xy(pos)
+            .into_iter()
+            .flat_map(move |(x, y)| adjacent(width, height, x, y, &DIRECTIONS))
+            .map(|(x, y)| Self::pos(x, y))
+    }
+
+    /// Iterate over the eight neighbors of a position, within the limits of the grid.
+    fn neighbors8(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        let (width, height) = (self.width(), self.height());
+        self.xy(pos)
+            .into_iter()
+            .flat_map(move |(x, y)| adjacent(width, height, x, y, &NEIGHBORS))
+            .map(|(x, y)| Self::pos(x, y))
+    }
+
+    /// Returns the grid whose rows are the columns of this one.
+    #[must_use]
+    fn transpose(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut transposed = self.new_like(self.height(), self.width());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                *transposed.cell_mut(y, x) = self.cell(x, y).clone();
+            }
+        }
+        transposed
+    }
+
+    #[must_use]
+    fn rotate_clockwise(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut rotated = self.new_like(self.height(), self.width());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                *rotated.cell_mut(self.height() - 1 - y, x) = self.cell(x, y).clone();
+            }
+        }
+        rotated
+    }
+
+    #[must_use]
+    fn rotate_counterclockwise(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut rotated = self.new_like(self.height(), self.width());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                *rotated.cell_mut(y, self.width() - 1 - x) = self.cell(x, y).clone();
+            }
+        }
+        rotated
+    }
+
+    /// Symmetry about the Y axis
+    #[must_use]
+    fn flip_vertical(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut flipped = self.new_like(self.width(), self.height());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                *flipped.cell_mut(x, y) = self.cell(self.width() - 1 - x, y).clone();
+            }
+        }
+        flipped
+    }
+
+    /// Symmetry about the X axis
+    #[must_use]
+    fn flip_horizontal(&self) -> Self
+    where
+        Self: Sized,
+    {
+        let mut flipped = self.new_like(self.width(), self.height());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                *flipped.cell_mut(x, y) = self.cell(x, self.height() - 1 - y).clone();
+            }
+        }
+        flipped
+    }
+
+    /// Extract the sub-grid whose top left corner is at column `x` and row `y`.
+    /// The cells outside of this grid are left default.
+    #[must_use]
+    fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Self
+    where
+        Self: Sized,
+    {
+        let mut sub = self.new_like(width, height);
+        for sy in 0..height.min(self.height().saturating_sub(y)) {
+            for sx in 0..width.min(self.width().saturating_sub(x)) {
+                *sub.cell_mut(sx, sy) = self.cell(x + sx, y + sy).clone();
+            }
+        }
+        sub
+    }
+
+    /// Copy a sub-grid with its top left corner at column `x` and row `y`.
+    /// The cells outside of this grid are ignored.
+    fn put_sub_grid(&mut self, x: usize, y: usize, sub: &Self)
+    where
+        Self: Sized,
+    {
+        for sy in 0..sub.height().min(self.height().saturating_sub(y)) {
+            for sx in 0..sub.width().min(self.width().saturating_sub(x)) {
+                *self.cell_mut(x + sx, y + sy) = sub.cell(sx, sy).clone();
+            }
+        }
+    }
+
+    /// Write the rows of the grid, with a character for each cell.
+    ///
+    /// # Errors
+    /// If the formatter fails.
+    fn fmt_cells(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        c: impl Fn(&Self::Cell) -> char,
+    ) -> std::fmt::Result {
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                write!(f, "{}", c(self.cell(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the positions connected to `start` through the four directions, by cells that are `inside`,
+    /// in breadth-first order. The result is empty if `start` is not inside.
+    fn flood_fill(
+        &self,
+        start: Self::Pos,
+        mut inside: impl FnMut(&Self::Cell) -> bool,
+    ) -> Vec<Self::Pos> {
+        let width = self.width();
+        let Some((x, y)) = self.xy(start).filter(|&(x, y)| inside(self.cell(x, y))) else {
+            return Vec::new();
+        };
+
+        let mut seen = vec![false; width * self.height()];
+        seen[y * width + x] = true;
+
+        let mut filled = vec![(x, y)];
+        let mut i = 0;
+        while let Some(&(x, y)) = filled.get(i) {
+            i += 1;
+            for (nx, ny) in adjacent(width, self.height(), x, y, &DIRECTIONS) {
+                if !seen[ny * width + nx] && inside(self.cell(nx, ny)) {
+                    seen[ny * width + nx] = true;
+                    filled.push((nx, ny));
+                }
+            }
+        }
+
+        filled.into_iter().map(|(x, y)| Self::pos(x, y)).collect()
+    }
+
+    /// Returns the regions of cells connected through the four directions, two adjacent cells being
+    /// in the same region if they are `connected`, e.g. equal. The regions and their positions are
+    /// in the order of their first cell, row by row.
+    fn components(
+        &self,
+        mut connected: impl FnMut(&Self::Cell, &Self::Cell) -> bool,
+    ) -> Vec<Vec<Self::Pos>> {
+        let (width, height) = (self.width(), self.height());
+        let mut uf = UnionFind::new(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                if x + 1 < width && connected(self.cell(x, y), self.cell(x + 1, y)) {
+                    uf.unite(y * width + x, y * width + x + 1);
+                }
+                if y + 1 < height && connected(self.cell(x, y), self.cell(x, y + 1)) {
+                    uf.unite(y * width + x, (y + 1) * width + x);
+                }
+            }
+        }
+
+        let mut region = vec![usize::MAX; width * height];
+        let mut regions: Vec<Vec<Self::Pos>> = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let root = uf.find(y * width + x);
+                if region[root] == usize::MAX {
+                    region[root] = regions.len();
+                    regions.push(Vec::new());
+                }
+                regions[region[root]].push(Self::pos(x, y));
+            }
+        }
+        regions
+    }
+}
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const NEIGHBORS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Iterate over the moves of `(x, y)` that stay within the limits.
+fn adjacent(
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    moves: &'static [(isize, isize)],
+) -> impl Iterator<Item = (usize, usize)> {
+    moves.iter().filter_map(move |&(dx, dy)| {
+        let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+        Some((x, y))
+    })
+}
+
+//
+// implementations
+//
+
+impl<T: Clone + Default> GridLike for Grid<T> {
+    type Cell = T;
+    type Pos = Coord;
+
+    #[inline]
+    fn width(&self) -> usize {
+        usize::try_from(Self::width(self)).unwrap_or_default()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        usize::try_from(Self::height(self)).unwrap_or_default()
+    }
+
+    #[inline]
+    fn cell(&self, x: usize, y: usize) -> &T {
+        &self[Self::pos(x, y)]
+    }
+
+    #[inline]
+    fn cell_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self[Self::pos(x, y)]
+    }
+
+    fn new_like(&self, width: usize, height: usize) -> Self {
+        Self::with_size(
+            i32::try_from(width).unwrap_or_default(),
+            i32::try_from(height).unwrap_or_default(),
+            T::default(),
+            self.exterior().clone(),
+        )
+    }
+
+    #[inline]
+    fn pos(x: usize, y: usize) -> Coord {
+        Coord::new(
+            i32::try_from(x).unwrap_or(i32::MAX),
+            i32::try_from(y).unwrap_or(i32::MAX),
+        )
+    }
+
+    #[inline]
+    fn xy(&self, pos: Coord) -> Option<(usize, usize)> {
+        let pos = self.is_in_grid(pos)?;
+        Some((usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?))
+    }
+}
+
+impl<T: Clone + Default> GridLike for GridU<T> {
+    type Cell = T;
+    type Pos = (usize, usize);
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.size().0
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.size().1
+    }
+
+    #[inline]
+    fn cell(&self, x: usize, y: usize) -> &T {
+        &self[(x, y)]
+    }
+
+    #[inline]
+    fn cell_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self[(x, y)]
+    }
+
+    fn new_like(&self, width: usize, height: usize) -> Self {
+        Self::with_size(width, height)
+    }
+
+    #[inline]
+    fn pos(x: usize, y: usize) -> (usize, usize) {
+        (x, y)
+    }
+
+    #[inline]
+    fn xy(&self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        (x < self.size().0 && y < self.size().1).then_some((x, y))
+    }
+}
+
+impl<T: Copy + Default> GridLike for Square<T> {
+    type Cell = T;
+    type Pos = (usize, usize);
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.size()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.size()
+    }
+
+    #[inline]
+    fn cell(&self, x: usize, y: usize) -> &T {
+        &self[(x, y)]
+    }
+
+    #[inline]
+    fn cell_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self[(x, y)]
+    }
+
+    /// # Panics
+    /// If the dimensions are not equal.
+    fn new_like(&self, width: usize, height: usize) -> Self {
+        assert_eq!(width, height, "a square has equal dimensions");
+        Self::new(width)
+    }
+
+    #[inline]
+    fn pos(x: usize, y: usize) -> (usize, usize) {
+        (x, y)
+    }
+
+    #[inline]
+    fn xy(&self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        (x < self.size() && y < self.size()).then_some((x, y))
+    }
+}
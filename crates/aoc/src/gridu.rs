@@ -7,6 +7,7 @@
 use std::ops::{Index, IndexMut};
 
 use crate::Direction;
+use crate::GridLike;
 
 /// A rectangular grid of elements, used to store various data including mazes.
 #[derive(Debug, Clone)]
@@ -38,47 +39,19 @@ impl<T: Clone + Default> GridU<T> {
 
 impl<T: Copy + Default> GridU<T> {
     pub fn rotate_clockwise(&self) -> Self {
-        let mut rotated = Self::with_size(self.height, self.width);
-
-        for x in 0..self.width {
-            for y in 0..self.height {
-                rotated[(self.height - y - 1, x)] = self[(x, y)];
-            }
-        }
-        rotated
+        GridLike::rotate_clockwise(self)
     }
 
     pub fn rotate_counterclockwise(&self) -> Self {
-        let mut rotated = Self::with_size(self.height, self.width);
-
-        for x in 0..self.width {
-            for y in 0..self.height {
-                rotated[(y, self.width - 1 - x)] = self[(x, y)];
-            }
-        }
-        rotated
+        GridLike::rotate_counterclockwise(self)
     }
 
     pub fn flip_vertical(&self) -> Self {
-        let mut flipped = Self::with_size(self.width, self.height);
-
-        for y in 0..self.height {
-            for x in 0..self.width {
-                flipped[(x, y)] = self[(self.width - 1 - x, y)];
-            }
-        }
-        flipped
+        GridLike::flip_vertical(self)
     }
 
     pub fn flip_horizontal(&self) -> Self {
-        let mut flipped = Self::with_size(self.width, self.height);
-
-        for y in 0..self.height {
-            for x in 0..self.width {
-                flipped[(x, y)] = self[(x, self.height - 1 - y)];
-            }
-        }
-        flipped
+        GridLike::flip_horizontal(self)
     }
 
     /// Iterate over the 8 possible images of the grid:
@@ -293,25 +266,12 @@ impl<T> IndexMut<(usize, usize)> for GridU<T> {
 
 impl std::fmt::Display for GridU<char> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                write!(f, "{}", self.g[self.width * y + x])?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.fmt_cells(f, |&c| c)
     }
 }
 
 impl std::fmt::Display for GridU<u8> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let c = self.g[self.width * y + x];
-                write!(f, "{}", c as char)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.fmt_cells(f, |&c| c as char)
     }
 }
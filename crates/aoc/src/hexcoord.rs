@@ -0,0 +1,171 @@
+//! `HexCoord` class represents a hexagon of a hexagonal grid, in axial coordinates.
+//!
+//! The cube coordinates are `q`, `r` and `s = -q - r`.
+//! The directions are named for pointy-top hexagons (`e`, `ne`, `nw`, `w`, `sw`, `se`)
+//! or flat-top hexagons (`n`, `ne`, `nw`, `s`, `sw`, `se`), but the `nw` and `se` differ.
+//!
+//! Advent-of-Rust 2024
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct HexCoord {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl HexCoord {
+    pub const ZERO: Self = Self { q: 0, r: 0 };
+
+    /// The six directions, counterclockwise from the direction of `q`.
+    pub const DIRECTIONS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(1, -1),
+        Self::new(0, -1),
+        Self::new(-1, 0),
+        Self::new(-1, 1),
+        Self::new(0, 1),
+    ];
+
+    #[inline]
+    #[must_use]
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// The third cube coordinate.
+    #[inline]
+    #[must_use]
+    pub const fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Returns the direction of pointy-top hexagons: `e`, `ne`, `nw`, `w`, `sw` or `se`.
+    #[must_use]
+    pub fn from_pointy(direction: &str) -> Option<Self> {
+        let i = ["e", "ne", "nw", "w", "sw", "se"]
+            .iter()
+            .position(|&d| d == direction)?;
+        Some(Self::DIRECTIONS[i])
+    }
+
+    /// Returns the direction of flat-top hexagons: `n`, `ne`, `nw`, `s`, `sw` or `se`.
+    #[must_use]
+    pub fn from_flat(direction: &str) -> Option<Self> {
+        let i = ["se", "ne", "n", "nw", "sw", "s"]
+            .iter()
+            .position(|&d| d == direction)?;
+        Some(Self::DIRECTIONS[i])
+    }
+
+    /// Number of steps between two hexagons.
+    #[inline]
+    #[must_use]
+    pub const fn distance(self, rhs: Self) -> i32 {
+        let d = Self::new(self.q - rhs.q, self.r - rhs.r);
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    /// Same as [`HexCoord::distance`], i.e. the half of the Manhattan distance of the cube coordinates.
+    #[inline]
+    #[must_use]
+    pub const fn manhattan_distance(self, rhs: Self) -> i32 {
+        self.distance(rhs)
+    }
+
+    /// Rotate a sixth of turn clockwise around the origin.
+    #[inline]
+    #[must_use]
+    pub const fn clockwise(self) -> Self {
+        Self {
+            q: -self.r,
+            r: -self.s(),
+        }
+    }
+
+    /// Rotate a sixth of turn counterclockwise around the origin.
+    #[inline]
+    #[must_use]
+    pub const fn counter_clockwise(self) -> Self {
+        Self {
+            q: -self.s(),
+            r: -self.q,
+        }
+    }
+
+    /// Returns an iterator over the six neighbors.
+    pub fn iter_neighbors(self) -> impl Iterator<Item = Self> {
+        Self::DIRECTIONS.iter().map(move |&d| self + d)
+    }
+}
+
+impl Add for HexCoord {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+
+impl AddAssign for HexCoord {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+
+impl SubAssign for HexCoord {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl Mul<i32> for HexCoord {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        Self {
+            q: self.q * rhs,
+            r: self.r * rhs,
+        }
+    }
+}
+
+impl Mul<HexCoord> for i32 {
+    type Output = HexCoord;
+    #[inline]
+    fn mul(self, other: HexCoord) -> HexCoord {
+        other * self
+    }
+}
+
+impl MulAssign<i32> for HexCoord {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        self.q *= rhs;
+        self.r *= rhs;
+    }
+}
+
+impl std::fmt::Display for HexCoord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
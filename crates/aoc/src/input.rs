@@ -0,0 +1,62 @@
+use std::error::Error;
+
+/// An error in a puzzle input, at a given position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column number in characters, starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl InputError {
+    #[must_use]
+    pub fn new(line: usize, column: usize, message: &str) -> Self {
+        Self {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    /// Build an error at the byte `offset` of `data`.
+    #[must_use]
+    pub fn at(data: &str, offset: usize, message: &str) -> Self {
+        let before = &data[..offset.min(data.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |pos| &before[pos + 1..])
+            .chars()
+            .count()
+            + 1;
+        Self::new(line, column, message)
+    }
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for InputError {}
+
+/// Remove the byte order mark and convert the CRLF line endings to LF.
+#[must_use]
+pub fn normalize_input(mut data: String) -> String {
+    if data.starts_with('\u{feff}') {
+        data.drain(..'\u{feff}'.len_utf8());
+    }
+
+    if data.contains("\r\n") {
+        data = data.replace("\r\n", "\n");
+    }
+
+    data
+}
@@ -1,26 +1,42 @@
 mod args;
 mod coord;
+mod coord3;
+mod coordn;
 mod counter;
+pub mod cycle;
 mod direction;
 mod dsu;
+mod dyngrid;
 mod grid;
+mod gridlike;
 mod gridu;
+mod hexcoord;
 pub mod hexslice;
+mod input;
 pub mod integer;
 pub mod knot;
 pub mod math;
 pub mod ocr;
+pub mod search;
+mod sparsegrid;
 mod square;
 mod unwraperror;
 pub mod util;
 
-pub use args::Args;
+pub use args::{Args, Command, Opt, Spec};
+pub use gridlike::GridLike;
+pub use input::{InputError, normalize_input};
 pub use unwraperror::DAMN;
 
 pub type Coord = coord::Coord;
+pub type Coord3 = coord3::Coord3;
+pub type CoordN<const D: usize> = coordn::CoordN<D>;
+pub type HexCoord = hexcoord::HexCoord;
 pub type Direction = direction::Direction;
 pub type Grid<T> = grid::Grid<T>;
 pub type GridU<T> = gridu::GridU<T>;
+pub type DynGrid<T> = dyngrid::DynGrid<T>;
+pub type SparseGrid<T> = sparsegrid::SparseGrid<T>;
 pub type Square<T> = square::Square<T>;
 pub type Counter<T> = counter::Counter<T>;
 
@@ -73,22 +89,39 @@ pub fn parse_args() -> Args {
     Args::parse_args()
 }
 
+/// Parse commandline arguments with extra options, and load input file.
+///
+/// The extra options are listed by `--help`, and the unknown options are rejected.
+///
+/// ```no_run
+/// let args = aoc::parse_args_with(&[aoc::Opt::flag("--z3", "Solve part 2 with Z3")]);
+/// if args.has_option("--z3") {
+///     // ...
+/// }
+/// ```
+#[must_use]
+pub fn parse_args_with(options: &[Opt]) -> Args {
+    Args::parse_args_with(options)
+}
+
 #[must_use]
 pub fn parse_args_raw() -> Args {
     Args::parse_args_raw()
 }
 
-/// Read the puzzle input.
+/// Read the puzzle input, without byte order mark and with LF line endings.
 /// # Panics
 /// If the file cannot be found or read
 #[must_use]
 pub fn load_input_data(filename: &str) -> String {
-    if filename == "-" {
+    let data = if filename == "-" {
         std::fs::read_to_string("/dev/stdin").unwrap()
     } else if std::path::Path::new(filename).is_file() {
         std::fs::read_to_string(filename).unwrap()
     } else {
         eprintln!("error: cannot read file {filename}");
         std::process::exit(1);
-    }
+    };
+
+    normalize_input(data)
 }
@@ -0,0 +1,364 @@
+//! Shortest path searches (BFS, 0-1 BFS, Dijkstra, A*) over a successor closure.
+//!
+//! All searches start from one or more sources, stop once every goal at the best cost is reached,
+//! and keep the predecessors of each node on a shortest path. The returned [`Search`] gives the costs,
+//! a path to any reached node and the nodes on any shortest path.
+//!
+//! ```
+//! use aoc::search;
+//!
+//! // smallest number of +1 or *2 steps from 1 to 10
+//! let search = search::bfs([1], |&n| [n + 1, n * 2], |&n| n == 10);
+//! assert_eq!(search.goal_cost(), Some(4));
+//! assert_eq!(search.path(&10).unwrap(), [1, 2, 4, 5, 10]);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::{Coord, Grid};
+
+/// The result of a search: the reached nodes with their costs and predecessors on the shortest paths.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    cost: Vec<C>,
+    preds: Vec<Vec<usize>>,
+    settled: Vec<bool>,
+    sources: usize,
+    goals: Vec<N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default,
+{
+    fn new(sources: impl IntoIterator<Item = N>) -> Self {
+        let mut search = Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            cost: Vec::new(),
+            preds: Vec::new(),
+            settled: Vec::new(),
+            sources: 0,
+            goals: Vec::new(),
+        };
+
+        for source in sources {
+            if let Entry::Vacant(entry) = search.index.entry(source.clone()) {
+                entry.insert(search.nodes.len());
+                search.nodes.push(source);
+                search.cost.push(C::default());
+                search.preds.push(Vec::new());
+                search.settled.push(false);
+            }
+        }
+        search.sources = search.nodes.len();
+
+        search
+    }
+
+    /// Record that `v` is reached from `u` with `cost`.
+    ///
+    /// Returns the index of `v` if its cost has decreased, i.e. if it has to be visited.
+    /// The sources and the settled nodes keep their predecessors, so that they never form a cycle,
+    /// even with moves that cost nothing.
+    fn relax(&mut self, u: usize, v: N, cost: C) -> Option<usize> {
+        match self.index.entry(v) {
+            Entry::Vacant(entry) => {
+                let v = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(v);
+                self.cost.push(cost);
+                self.preds.push(vec![u]);
+                self.settled.push(false);
+                Some(v)
+            }
+            Entry::Occupied(entry) => {
+                let v = *entry.get();
+                if self.settled[v] || v < self.sources {
+                    None
+                } else if cost < self.cost[v] {
+                    self.cost[v] = cost;
+                    self.preds[v].clear();
+                    self.preds[v].push(u);
+                    Some(v)
+                } else {
+                    if cost == self.cost[v] && !self.preds[v].contains(&u) {
+                        self.preds[v].push(u);
+                    }
+                    None
+                }
+            }
+        }
+    }
+
+    /// Settle the node `u` popped with `cost`.
+    ///
+    /// Returns false if the node has to be skipped, i.e. it is already settled or `cost` is stale.
+    fn settle(&mut self, u: usize, cost: C, goal: &mut impl FnMut(&N) -> bool) -> bool {
+        if self.settled[u] || cost > self.cost[u] {
+            return false;
+        }
+
+        self.settled[u] = true;
+        if goal(&self.nodes[u]) {
+            self.goals.push(self.nodes[u].clone());
+        }
+        true
+    }
+
+    /// Whether all goals at the best cost are reached, for a search in order of cost.
+    fn is_over(&self, cost: C) -> bool {
+        self.goal_cost().is_some_and(|best| cost > best)
+    }
+
+    /// Cost of the first reached goal, i.e. the cost of the shortest path to a goal.
+    #[must_use]
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    /// The first reached goal.
+    #[must_use]
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    /// All goals reached at the best cost.
+    #[must_use]
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Cost of the shortest path from a source to `node`, if it has been reached.
+    #[must_use]
+    pub fn cost(&self, node: &N) -> Option<C> {
+        let &u = self.index.get(node)?;
+        self.settled[u].then_some(self.cost[u])
+    }
+
+    /// Iterate over the reached nodes and their costs.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes
+            .iter()
+            .zip(&self.cost)
+            .zip(&self.settled)
+            .filter(|&(_, &settled)| settled)
+            .map(|((node, &cost), _)| (node, cost))
+    }
+
+    /// Iterate over the predecessors of `node` on the shortest paths.
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.index
+            .get(node)
+            .into_iter()
+            .flat_map(|&u| &self.preds[u])
+            .map(|&p| &self.nodes[p])
+    }
+
+    /// A shortest path from a source to `node`, both included.
+    #[must_use]
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut u = *self.index.get(node)?;
+        if !self.settled[u] {
+            return None;
+        }
+
+        let mut path = vec![self.nodes[u].clone()];
+        while let Some(&p) = self.preds[u].first() {
+            path.push(self.nodes[p].clone());
+            u = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The nodes on any shortest path from a source to one of `targets`.
+    #[must_use]
+    pub fn on_shortest_paths(&self, targets: &[N]) -> HashSet<&N> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack: Vec<_> = targets
+            .iter()
+            .filter_map(|target| self.index.get(target).copied())
+            .filter(|&u| self.settled[u])
+            .collect();
+
+        while let Some(u) = stack.pop() {
+            if !seen[u] {
+                seen[u] = true;
+                stack.extend(self.preds[u].iter().filter(|&&p| !seen[p]));
+            }
+        }
+
+        self.nodes
+            .iter()
+            .zip(seen)
+            .filter_map(|(node, seen)| seen.then_some(node))
+            .collect()
+    }
+
+    /// Count the distinct keys of the nodes on any shortest path to one of `targets`,
+    /// e.g. the tiles of states made of a position and a direction.
+    pub fn count_on_shortest_paths<K>(&self, targets: &[N], key: impl Fn(&N) -> K) -> usize
+    where
+        K: Eq + Hash,
+    {
+        self.on_shortest_paths(targets)
+            .into_iter()
+            .map(key)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+/// Breadth-first search, where each move costs 1.
+///
+/// The search explores all reachable nodes if `goal` is never true.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(sources);
+    let mut queue: VecDeque<usize> = (0..search.nodes.len()).collect();
+
+    while let Some(u) = queue.pop_front() {
+        let cost = search.cost[u];
+        if search.is_over(cost) {
+            break;
+        }
+        if !search.settle(u, cost, &mut goal) {
+            continue;
+        }
+
+        for v in successors(&search.nodes[u]) {
+            if let Some(v) = search.relax(u, v, cost + 1) {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    search
+}
+
+/// 0-1 breadth-first search, where each move costs 0 or 1.
+///
+/// # Panics
+/// If a move costs more than 1.
+pub fn bfs01<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new(sources);
+    let mut queue: VecDeque<(usize, usize)> = (0..search.nodes.len()).map(|u| (0, u)).collect();
+
+    while let Some((cost, u)) = queue.pop_front() {
+        if search.is_over(cost) {
+            break;
+        }
+        if !search.settle(u, cost, &mut goal) {
+            continue;
+        }
+
+        for (v, weight) in successors(&search.nodes[u]) {
+            assert!(weight <= 1, "0-1 BFS move costs {weight}");
+
+            if let Some(v) = search.relax(u, v, cost + weight) {
+                if weight == 0 {
+                    queue.push_front((cost, v));
+                } else {
+                    queue.push_back((cost + 1, v));
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's search, where each move has a non-negative cost.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(sources, successors, |_| C::default(), goal)
+}
+
+/// A* search, guided by a `heuristic` that never overestimates the cost to a goal
+/// and is consistent, so that the nodes are settled once with their best cost.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(sources);
+    let mut heap: BinaryHeap<_> = (0..search.nodes.len())
+        .map(|u| Reverse((heuristic(&search.nodes[u]), C::default(), u)))
+        .collect();
+
+    while let Some(Reverse((estimate, cost, u))) = heap.pop() {
+        // with a consistent heuristic, the estimate of a goal is its cost
+        if search.is_over(estimate) {
+            break;
+        }
+        if !search.settle(u, cost, &mut goal) {
+            continue;
+        }
+
+        for (v, weight) in successors(&search.nodes[u]) {
+            let cost = cost + weight;
+            let estimate = cost + heuristic(&v);
+            if let Some(v) = search.relax(u, v, cost) {
+                heap.push(Reverse((estimate, cost, v)));
+            }
+        }
+    }
+
+    search
+}
+
+/// Breadth-first search on a grid, moving in the four directions onto the `passable` cells.
+pub fn grid_bfs<T: Clone + Default>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Coord>,
+    passable: impl Fn(&T) -> bool,
+    goal: impl FnMut(&Coord) -> bool,
+) -> Search<Coord, usize> {
+    bfs(
+        sources,
+        |&pos| {
+            grid.iter_directions(pos)
+                .map(|(_, next)| next)
+                .filter(|&next| passable(&grid[next]))
+                .collect::<Vec<_>>()
+        },
+        goal,
+    )
+}
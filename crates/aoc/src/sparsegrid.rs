@@ -0,0 +1,204 @@
+//! `SparseGrid` class, a grid that stores only its written cells.
+//!
+//! Advent-of-Rust 2024
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::grid::{DIRECTIONS, NEIGHBORS, render};
+use crate::{Coord, Direction, Grid};
+
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    default: T,                     // value of the cells never written
+    bounds: Option<(Coord, Coord)>, // bounding box of the written cells
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Construct a new, empty `SparseGrid<T>`, whose cells are `default` until written.
+    #[must_use]
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Return the number of written cells.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if no cell is written.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Test if a cell is written.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, pos: Coord) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Write a cell, returning its previous value if it was written.
+    pub fn insert(&mut self, pos: Coord, value: T) -> Option<T> {
+        self.extend(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Forget a cell, that becomes `default` again.
+    pub fn remove(&mut self, pos: Coord) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+
+        // only a cell on the edge can shrink the bounding box
+        if self.bounds.is_some_and(|(min, max)| {
+            pos.x == min.x || pos.x == max.x || pos.y == min.y || pos.y == max.y
+        }) {
+            self.bounds = None;
+            let cells: Vec<_> = self.cells.keys().copied().collect();
+            for pos in cells {
+                self.extend(pos);
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Include `pos` into the bounding box.
+    fn extend(&mut self, pos: Coord) {
+        self.bounds = Some(self.bounds.map_or((pos, pos), |(min, max)| {
+            (Coord::new(min.x.min(pos.x), min.y.min(pos.y)), max.max(pos))
+        }));
+    }
+
+    /// Return the bounding box of the written cells, as its top left and bottom right corners.
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    /// Return the width of the bounding box.
+    #[must_use]
+    pub fn width(&self) -> i32 {
+        self.bounds.map_or(0, |(min, max)| max.x - min.x + 1)
+    }
+
+    /// Return the height of the bounding box.
+    #[must_use]
+    pub fn height(&self) -> i32 {
+        self.bounds.map_or(0, |(min, max)| max.y - min.y + 1)
+    }
+
+    /// Iterate over the written cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&pos, c)| (pos, c))
+    }
+
+    /// Returns an iterator over the all four directions.
+    pub fn iter_directions(&self, xy: Coord) -> impl Iterator<Item = (Direction, Coord)> + '_ {
+        DIRECTIONS.iter().map(move |&(d, dxy)| (d, xy + dxy))
+    }
+
+    /// Returns an iterator over the all eight neighbors.
+    pub fn iter_neighbors(&self, xy: Coord) -> impl Iterator<Item = Coord> + '_ {
+        NEIGHBORS.iter().map(move |&dxy| xy + dxy)
+    }
+}
+
+impl<T: Clone + Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone + Default + PartialEq> From<&Grid<T>> for SparseGrid<T> {
+    /// Copy the cells of a grid that are not its exterior, which becomes the default value.
+    fn from(grid: &Grid<T>) -> Self {
+        let mut sparse = Self::new(grid.exterior().clone());
+        for (pos, c) in grid {
+            if *c != sparse.default {
+                sparse.insert(pos, c.clone());
+            }
+        }
+        sparse
+    }
+}
+
+//
+// implement []
+//
+
+impl<T: Clone> Index<Coord> for SparseGrid<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, pos: Coord) -> &Self::Output {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+}
+
+impl<T: Clone> IndexMut<Coord> for SparseGrid<T> {
+    /// Return the cell, writing `default` into it if needed.
+    #[inline]
+    fn index_mut(&mut self, pos: Coord) -> &mut Self::Output {
+        self.extend(pos);
+        self.cells
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+//
+// char and u8 specializations
+//
+
+impl From<&str> for SparseGrid<char> {
+    /// Read a grid from a puzzle input, keeping the cells that are not `.`.
+    fn from(input: &str) -> Self {
+        let mut grid = Self::new('.');
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, c) in (0..).zip(line.chars()) {
+                if c != '.' {
+                    grid.insert(Coord::new(x, y), c);
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl From<&str> for SparseGrid<u8> {
+    /// Read a grid from a puzzle input, keeping the cells that are not `.`.
+    fn from(input: &str) -> Self {
+        let mut grid = Self::new(b'.');
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, &c) in (0..).zip(line.as_bytes()) {
+                if c != b'.' {
+                    grid.insert(Coord::new(x, y), c);
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl std::fmt::Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.bounds()
+            .map_or(Ok(()), |bounds| render(f, bounds, |pos| self[pos]))
+    }
+}
+
+impl std::fmt::Display for SparseGrid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.bounds()
+            .map_or(Ok(()), |bounds| render(f, bounds, |pos| self[pos] as char))
+    }
+}
@@ -3,6 +3,8 @@ use std::{
     ops::{Index, IndexMut},
 };
 
+use crate::GridLike;
+
 /// A square grid of elements `T`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Square<T> {
@@ -69,39 +71,19 @@ impl<T: Clone + Copy + Default> Square<T> {
     /// Clockwise rotation
     #[inline]
     pub fn rotate(&self) -> Self {
-        let mut rotated = Self::new(self.n);
-        for x in 0..self.n {
-            for y in 0..self.n {
-                rotated[(x, y)] = self[(y, self.n - 1 - x)];
-            }
-        }
-        rotated
+        self.rotate_clockwise()
     }
 
     /// Symmetry about the Y axis
     #[inline]
     pub fn flip_vertical(&self) -> Self {
-        let mut flipped = Self::new(self.n);
-        let n = self.n;
-        for y in 0..n {
-            for x in 0..n {
-                flipped[(x, y)] = self[(n - 1 - x, y)];
-            }
-        }
-        flipped
+        GridLike::flip_vertical(self)
     }
 
     /// Symmetry about the X axis
     #[inline]
     pub fn flip_horizontal(&self) -> Self {
-        let mut flipped = Self::new(self.n);
-        let n = self.n;
-        for y in 0..n {
-            for x in 0..n {
-                flipped[(x, y)] = self[(x, n - 1 - y)];
-            }
-        }
-        flipped
+        GridLike::flip_horizontal(self)
     }
 
     /// Return the cells as a flattened slice `[row1, row2, ..., rowN]`
@@ -161,26 +143,12 @@ impl<T: Clone + Copy + Default> Square<T> {
     /// Extract a subsquare from a bigger one.
     #[inline]
     pub fn get_square(&self, offset_x: usize, offset_y: usize, n: usize) -> Self {
-        let mut subsquare = Self::new(n);
-        for y in 0..n {
-            for x in 0..n {
-                if offset_x + x < self.n && offset_y + y < self.n {
-                    subsquare[(x, y)] = self[(offset_x + x, offset_y + y)];
-                }
-            }
-        }
-        subsquare
+        self.sub_grid(offset_x, offset_y, n, n)
     }
 
     #[inline]
     pub fn put_square(&mut self, offset_x: usize, offset_y: usize, subsquare: &Self) {
-        for y in 0..subsquare.n {
-            for x in 0..subsquare.n {
-                if offset_x + x < self.n && offset_y + y < self.n {
-                    self[(offset_x + x, offset_y + y)] = subsquare[(x, y)];
-                }
-            }
-        }
+        self.put_sub_grid(offset_x, offset_y, subsquare);
     }
 }
 
@@ -250,14 +218,7 @@ impl Square<u8> {
 
 impl std::fmt::Display for Square<u8> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.n {
-            for x in 0..self.n {
-                let idx = self.n * y + x;
-                write!(f, "{}", char::from_u32(u32::from(self.cells[idx])).unwrap())?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.fmt_cells(f, |&c| c as char)
     }
 }
 
@@ -0,0 +1,123 @@
+use aoc::{Args, Command, Opt, Spec};
+
+const OPTIONS: [Opt; 4] = [
+    Opt::flag("--alt", "Include the alternatives").short('a'),
+    Opt::flag("--verbose", "Use verbose output").short('v'),
+    Opt::with_value("--jobs", "N", "Number of threads").short('j'),
+    Opt::with_value("--format", "FORMAT", "Output format"),
+];
+
+const BENCH_OPTIONS: [Opt; 1] = [Opt::with_value("--runs", "N", "Number of runs")];
+
+const COMMANDS: [Command; 2] = [
+    Command {
+        name: "run",
+        help: "Run the solutions",
+        options: &[],
+    },
+    Command {
+        name: "bench",
+        help: "Benchmark the solutions",
+        options: &BENCH_OPTIONS,
+    },
+];
+
+fn spec() -> Spec<'static> {
+    Spec {
+        about: "test",
+        params: "[FILTER]",
+        options: &OPTIONS,
+        commands: &COMMANDS,
+        default_command: Some("run"),
+        lenient: false,
+    }
+}
+
+fn parse(args: &[&str]) -> Result<Args, String> {
+    Args::try_parse_from(&spec(), args.iter().copied())
+}
+
+#[test]
+fn args_flags() {
+    let args = parse(&["-av", "2015"]).unwrap();
+
+    assert!(args.has_option("--alt"));
+    assert!(args.has_option("-a"));
+    assert!(args.has_option("-v"));
+    assert!(args.is_verbose());
+    assert!(!args.has_option("--jobs"));
+    assert_eq!(args.params(), ["2015"]);
+    assert_eq!(args.command(), Some("run"));
+}
+
+#[test]
+fn args_values() {
+    for argv in [
+        ["--jobs=4", "--format=json"].as_slice(),
+        &["--jobs", "4", "--format", "json"],
+        &["-j4", "--format=json"],
+        &["-aj", "4", "--format=json"],
+    ] {
+        let args = parse(argv).unwrap();
+        assert_eq!(args.option_value("--jobs"), Some("4"));
+        assert_eq!(args.option_value("-j"), Some("4"));
+        assert_eq!(args.value_of::<usize>("--jobs"), Ok(Some(4)));
+        assert_eq!(args.option_value("--format"), Some("json"));
+    }
+
+    let args = parse(&["--jobs=four"]).unwrap();
+    assert!(args.value_of::<usize>("--jobs").is_err());
+    assert_eq!(args.value_of::<usize>("--runs"), Ok(None));
+}
+
+#[test]
+fn args_errors() {
+    assert!(parse(&["--unknown"]).is_err());
+    assert!(parse(&["-x"]).is_err());
+    assert!(parse(&["--jobs"]).is_err());
+    assert!(parse(&["--alt=1"]).is_err());
+
+    // options of another command
+    assert!(parse(&["--runs=3"]).is_err());
+    assert!(parse(&["run", "--runs=3"]).is_err());
+
+    // the lenient mode keeps the unknown options
+    let spec = Spec {
+        lenient: true,
+        ..spec()
+    };
+    let args = Args::try_parse_from(&spec, ["--unknown", "--db=memory", "-xyz"]).unwrap();
+    assert!(args.has_option("--unknown"));
+    assert!(args.has_option("-xyz"));
+    assert_eq!(args.option_value("--db"), Some("memory"));
+}
+
+#[test]
+fn args_commands() {
+    let args = parse(&["bench", "--runs=3", "2015:1"]).unwrap();
+    assert_eq!(args.command(), Some("bench"));
+    assert_eq!(args.option_value("--runs"), Some("3"));
+    assert_eq!(args.params(), ["2015:1"]);
+
+    // only the first parameter can be a command
+    let args = parse(&["2015", "bench"]).unwrap();
+    assert_eq!(args.command(), Some("run"));
+    assert_eq!(args.params(), ["2015", "bench"]);
+
+    let args = parse(&["--", "-1", "--jobs"]).unwrap();
+    assert_eq!(args.params(), ["-1", "--jobs"]);
+}
+
+#[test]
+fn args_help() {
+    colored::control::set_override(false);
+
+    let help = spec().help("aor");
+
+    assert!(help.contains("Usage: aor [OPTIONS] [COMMAND] [FILTER]\n"));
+    assert!(help.contains("  bench                Benchmark the solutions\n"));
+    assert!(help.contains("  -h, --help           Print help\n"));
+    assert!(help.contains("  -j, --jobs=N         Number of threads\n"));
+    assert!(help.contains("      --format=FORMAT  Output format\n"));
+    assert!(help.contains("Options of bench:\n      --runs=N         Number of runs\n"));
+}
@@ -0,0 +1,139 @@
+use aoc::{Coord, Coord3, CoordN, HexCoord};
+use std::collections::HashSet;
+
+#[test]
+fn coord3_ops() {
+    let a = Coord3::new(1, 2, 3);
+    let b = Coord3::new(-1, 2, -5);
+
+    assert_eq!(a + b, Coord3::new(0, 4, -2));
+    assert_eq!(a - b, Coord3::new(2, 0, 8));
+    assert_eq!(a * 2, Coord3::new(2, 4, 6));
+    assert_eq!(-1 * a, Coord3::new(-1, -2, -3));
+    assert_eq!(a.manhattan_distance(b), 10);
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= 3;
+    assert_eq!(c, b * 3);
+
+    assert_eq!(a.to_string(), "(1, 2, 3)");
+}
+
+#[test]
+fn coord3_neighbors() {
+    let a = Coord3::new(1, 2, 3);
+
+    assert_eq!(a.iter_faces().count(), 6);
+    assert!(a.iter_faces().all(|n| n.manhattan_distance(a) == 1));
+
+    let neighbors: HashSet<_> = a.iter_neighbors().collect();
+    assert_eq!(neighbors.len(), 26);
+    assert!(!neighbors.contains(&a));
+}
+
+#[test]
+fn coord3_rotations() {
+    let a = Coord3::new(1, 2, 3);
+
+    assert_eq!(a.rotate_z(), Coord3::new(-2, 1, 3));
+    assert_eq!(a.rotate_x().rotate_x().rotate_x().rotate_x(), a);
+    assert_eq!(a.rotate_y().rotate_y(), Coord3::new(-1, 2, -3));
+
+    let orientations: HashSet<_> = a.orientations().into_iter().collect();
+    assert_eq!(orientations.len(), 24);
+    assert!(orientations.contains(&a));
+    assert!(orientations.contains(&Coord3::new(-3, -2, -1)));
+    assert!(!orientations.contains(&Coord3::new(-1, -2, -3)));
+}
+
+#[test]
+fn coordn_ops() {
+    let a = CoordN::new([1, 2, 3, 4]);
+    let b = CoordN::new([0, -2, 3, 1]);
+
+    assert_eq!(a + b, CoordN::new([1, 0, 6, 5]));
+    assert_eq!(a - b, CoordN::new([1, 4, 0, 3]));
+    assert_eq!(2 * a, CoordN::new([2, 4, 6, 8]));
+    assert_eq!(a.manhattan_distance(b), 8);
+    assert_eq!(a[3], 4);
+    assert_eq!(a.rotate(0, 1), CoordN::new([-2, 1, 3, 4]));
+    assert_eq!(a.to_string(), "(1, 2, 3, 4)");
+
+    assert_eq!(CoordN::from(Coord::new(5, 6)), CoordN::new([5, 6]));
+    assert_eq!(CoordN::from(Coord3::new(5, 6, 7)), CoordN::new([5, 6, 7]));
+}
+
+#[test]
+fn coordn_neighbors() {
+    let a = CoordN::<4>::ZERO;
+
+    let neighbors: HashSet<_> = a.iter_neighbors().collect();
+    assert_eq!(neighbors.len(), 80);
+    assert!(!neighbors.contains(&a));
+
+    assert_eq!(a.iter_faces().count(), 8);
+    assert!(a.iter_faces().all(|n| n.manhattan_distance(a) == 1));
+}
+
+#[test]
+fn hexcoord_paths() {
+    let walk = |path: &str, from: fn(&str) -> Option<HexCoord>| {
+        path.split(',')
+            .map(|d| from(d).unwrap())
+            .fold(HexCoord::ZERO, |pos, d| pos + d)
+    };
+
+    // 2017 day 11, flat-top
+    assert_eq!(
+        walk("ne,ne,ne", HexCoord::from_flat).distance(HexCoord::ZERO),
+        3
+    );
+    assert_eq!(walk("ne,ne,sw,sw", HexCoord::from_flat), HexCoord::ZERO);
+    assert_eq!(
+        walk("ne,ne,s,s", HexCoord::from_flat).distance(HexCoord::ZERO),
+        2
+    );
+    assert_eq!(
+        walk("se,sw,se,sw,sw", HexCoord::from_flat).distance(HexCoord::ZERO),
+        3
+    );
+
+    // 2020 day 24, pointy-top
+    assert_eq!(walk("nw,w,sw,e,e", HexCoord::from_pointy), HexCoord::ZERO);
+    assert_eq!(
+        walk("e,se,w", HexCoord::from_pointy),
+        HexCoord::from_pointy("se").unwrap()
+    );
+
+    assert_eq!(HexCoord::from_flat("e"), None);
+    assert_eq!(HexCoord::from_pointy("n"), None);
+}
+
+#[test]
+fn hexcoord_ops() {
+    let a = HexCoord::new(2, -1);
+
+    assert_eq!(a.s(), -1);
+    assert_eq!(a * 3 - a, 2 * a);
+    assert_eq!(a.manhattan_distance(HexCoord::ZERO), 2);
+    assert_eq!(a.to_string(), "(2, -1, -1)");
+
+    assert_eq!(a.iter_neighbors().count(), 6);
+    assert!(a.iter_neighbors().all(|n| n.distance(a) == 1));
+
+    let east = HexCoord::from_pointy("e").unwrap();
+    assert_eq!(east.clockwise(), HexCoord::from_pointy("se").unwrap());
+    assert_eq!(
+        east.counter_clockwise(),
+        HexCoord::from_pointy("ne").unwrap()
+    );
+
+    let mut b = a;
+    for _ in 0..6 {
+        b = b.clockwise();
+        assert_eq!(b.distance(HexCoord::ZERO), 2);
+    }
+    assert_eq!(b, a);
+}
@@ -0,0 +1,92 @@
+use aoc::cycle::{self, Cycle};
+
+// 0, 1, 2, 5, 4, 6, 4, 6, ...
+fn step(x: &u32) -> u32 {
+    (x * x + 1) % 11
+}
+
+#[test]
+fn cycle_floyd_brent() {
+    let expected = Cycle {
+        start: 4,
+        length: 2,
+    };
+
+    assert_eq!(cycle::floyd(0, step), expected);
+    assert_eq!(cycle::brent(0, step), expected);
+    assert_eq!(
+        cycle::floyd(4, step),
+        Cycle {
+            start: 0,
+            length: 2
+        }
+    );
+    assert_eq!(
+        cycle::brent(7, |&x| x),
+        Cycle {
+            start: 0,
+            length: 1
+        }
+    );
+}
+
+#[test]
+fn cycle_find() {
+    let mut state = 0;
+    let cycle = cycle::find(&mut state, |x| *x = step(x), cycle::fingerprint);
+
+    assert_eq!(
+        cycle,
+        Cycle {
+            start: 4,
+            length: 2
+        }
+    );
+    assert_eq!(state, 4);
+
+    assert_eq!(cycle.reduce(3), 3);
+    assert_eq!(cycle.reduce(6), 4);
+    assert_eq!(cycle.reduce(1_000_000_001), 5);
+}
+
+#[test]
+fn cycle_nth() {
+    let nth = |n| cycle::nth(0, |x| *x = step(x), |&x| x, n);
+
+    assert_eq!(nth(0), 0);
+    assert_eq!(nth(3), 5);
+    assert_eq!(nth(6), 4);
+    assert_eq!(nth(1_000_000_001), 6);
+
+    // large states are remembered by their fingerprint
+    let grid = cycle::nth(
+        vec![vec![0_u8; 100]; 100],
+        |grid| grid[0][0] = (grid[0][0] + 1) % 3,
+        cycle::fingerprint,
+        1_000,
+    );
+    assert_eq!(grid[0][0], 1);
+}
+
+#[test]
+fn cycle_extrapolate() {
+    // the height grows by 2, 3, then 1 over each cycle of 3 steps
+    let height = |n| {
+        cycle::extrapolate(
+            (0, 0),
+            |(phase, height)| {
+                *phase = (*phase + 1) % 3;
+                *height += *phase + 1;
+            },
+            |&(phase, _)| phase,
+            |&(_, height)| height,
+            n,
+        )
+    };
+
+    assert_eq!(height(0), 0);
+    assert_eq!(height(2), 5);
+    assert_eq!(height(3), 6);
+    assert_eq!(height(1_000_000_000), 2_000_000_000);
+    assert_eq!(height(1_000_000_001), 2_000_000_003);
+}
@@ -0,0 +1,129 @@
+//
+// Integration tests of the growing grids
+//
+
+use aoc::{Coord, Direction, DynGrid, Grid, SparseGrid};
+
+const GLIDER: &str = "\
+.#.
+..#
+###
+";
+
+#[test]
+fn dyngrid_grow() {
+    let mut grid = DynGrid::new('.');
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.to_string(), "");
+
+    grid[Coord::new(2, 1)] = 'A';
+    grid[Coord::new(-1, -2)] = 'B';
+    grid[Coord::new(100, -50)] = 'C';
+
+    assert_eq!(grid[Coord::new(2, 1)], 'A');
+    assert_eq!(grid[Coord::new(-1, -2)], 'B');
+    assert_eq!(grid[Coord::new(100, -50)], 'C');
+    assert_eq!(grid[Coord::new(0, 0)], '.');
+    assert_eq!(grid[Coord::new(1000, 1000)], '.');
+
+    assert_eq!(
+        grid.bounds(),
+        Some((Coord::new(-1, -50), Coord::new(100, 1)))
+    );
+    assert_eq!((grid.width(), grid.height()), (102, 52));
+    assert_eq!(grid.iter().filter(|&(_, &c)| c != '.').count(), 3);
+}
+
+#[test]
+fn dyngrid_display() {
+    let mut grid = DynGrid::<char>::from(GLIDER);
+    assert_eq!(grid.to_string(), GLIDER);
+
+    grid[Coord::new(-1, 3)] = 'o';
+    assert_eq!(grid.to_string(), "..#.\n...#\n.###\no...\n");
+
+    let grid = DynGrid::<u8>::from(GLIDER);
+    assert_eq!(grid.to_string(), GLIDER);
+}
+
+#[test]
+fn sparsegrid_set_get() {
+    let mut grid = SparseGrid::<u8>::from(GLIDER);
+    assert_eq!(grid.len(), 5);
+    assert_eq!(grid.to_string(), GLIDER);
+
+    assert!(grid.contains(Coord::new(2, 1)));
+    assert_eq!(grid[Coord::new(2, 1)], b'#');
+    assert_eq!(grid[Coord::new(-7, 3)], b'.');
+
+    grid[Coord::new(-2, 0)] = b'o';
+    assert_eq!(grid.width(), 5);
+    assert_eq!(grid.to_string(), "o..#.\n....#\n..###\n");
+
+    assert_eq!(grid.remove(Coord::new(-2, 0)), Some(b'o'));
+    assert_eq!(grid.bounds(), Some((Coord::new(0, 0), Coord::new(2, 2))));
+
+    // the box shrinks only when its edge is emptied
+    grid.insert(Coord::new(1, 1), b'o');
+    assert_eq!(grid.remove(Coord::new(1, 1)), Some(b'o'));
+    assert_eq!(grid.bounds(), Some((Coord::new(0, 0), Coord::new(2, 2))));
+    assert_eq!(grid.remove(Coord::new(0, 2)), Some(b'#'));
+    assert_eq!(grid.bounds(), Some((Coord::new(1, 0), Coord::new(2, 2))));
+    assert_eq!(grid.remove(Coord::new(1, 0)), Some(b'#'));
+    assert_eq!(grid.bounds(), Some((Coord::new(1, 1), Coord::new(2, 2))));
+    assert_eq!(grid.remove(Coord::new(1, 0)), None);
+
+    for pos in [Coord::new(2, 1), Coord::new(1, 2), Coord::new(2, 2)] {
+        grid.remove(pos);
+    }
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.to_string(), "");
+}
+
+#[test]
+fn grids_same_api() {
+    let grid = Grid::<char>::parse(GLIDER, '.');
+    let mut dyngrid = DynGrid::from(&grid);
+    let mut sparse = SparseGrid::<char>::from(GLIDER);
+
+    let live = |pos: Coord, get: &dyn Fn(Coord) -> char| {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Coord::new(dx, dy)))
+            .filter(|&d| d != Coord::ZERO && get(pos + d) == '#')
+            .count()
+    };
+
+    for (pos, _) in &grid {
+        let n = dyngrid
+            .iter_neighbors(pos)
+            .filter(|&p| dyngrid[p] == '#')
+            .count();
+        assert_eq!(n, live(pos, &|p| sparse[p]));
+        assert_eq!(
+            n,
+            sparse
+                .iter_neighbors(pos)
+                .filter(|&p| sparse[p] == '#')
+                .count()
+        );
+    }
+
+    let dirs: Vec<_> = sparse.iter_directions(Coord::ZERO).collect();
+    assert_eq!(dirs[0], (Direction::North, Coord::new(0, -1)));
+    assert_eq!(dirs.len(), 4);
+    assert_eq!(dyngrid.to_string(), sparse.to_string());
+
+    // the exterior of the grid is the default of the new cells
+    let mut from_grid = SparseGrid::from(&grid);
+    assert_eq!(from_grid.len(), 5);
+    dyngrid[Coord::new(4, -1)] = 'o';
+    sparse[Coord::new(4, -1)] = 'o';
+    from_grid[Coord::new(4, -1)] = 'o';
+    assert_eq!(dyngrid.to_string(), "....o\n.#...\n..#..\n###..\n");
+    assert_eq!(dyngrid.to_string(), sparse.to_string());
+    assert_eq!(dyngrid.to_string(), from_grid.to_string());
+
+    let mut grid = Grid::<u8>::from(GLIDER);
+    grid.set_exterior(b'.');
+    assert_eq!(SparseGrid::from(&grid).len(), 5);
+}
@@ -0,0 +1,100 @@
+//
+// Integration tests
+//
+
+use aoc::{Coord, Grid, GridLike, GridU, Square};
+
+const MAP: &str = concat!(
+    "##.\n", //
+    "#..\n", //
+    ".#.\n", //
+);
+
+/// Render any grid of bytes with the trait only.
+fn render<G: GridLike<Cell = u8>>(grid: &G) -> String {
+    struct Render<'a, G>(&'a G);
+    impl<G: GridLike<Cell = u8>> std::fmt::Display for Render<'_, G> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt_cells(f, |&c| c as char)
+        }
+    }
+    Render(grid).to_string()
+}
+
+/// Check the transforms, the flood fill and the components of `MAP`.
+fn check<G: GridLike<Cell = u8>>(grid: &G) {
+    assert_eq!(render(grid), MAP);
+
+    assert_eq!(render(&grid.transpose()), "##.\n#.#\n...\n");
+    assert_eq!(render(&grid.rotate_clockwise()), ".##\n#.#\n...\n");
+    assert_eq!(render(&grid.rotate_counterclockwise()), "...\n#.#\n##.\n");
+    assert_eq!(render(&grid.flip_vertical()), ".##\n..#\n.#.\n");
+    assert_eq!(render(&grid.flip_horizontal()), ".#.\n#..\n##.\n");
+    assert_eq!(render(&grid.transpose().transpose()), MAP);
+
+    let start = G::pos(2, 0);
+    let filled = grid.flood_fill(start, |&c| c == b'.');
+    assert_eq!(filled.len(), 4);
+    assert_eq!(grid.xy(filled[0]), Some((2, 0)));
+    assert!(grid.flood_fill(G::pos(0, 0), |&c| c == b'.').is_empty());
+
+    // the wall at the top left, the open area, the lone '.' and the lone '#' at the bottom
+    let regions = grid.components(|a, b| a == b);
+    let sizes: Vec<_> = regions.iter().map(Vec::len).collect();
+    assert_eq!(sizes, [3, 4, 1, 1]);
+    assert_eq!(grid.xy(regions[2][0]), Some((0, 2)));
+
+    assert_eq!(grid.neighbors4(G::pos(0, 0)).count(), 2);
+    assert_eq!(grid.neighbors4(G::pos(1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8(G::pos(2, 2)).count(), 3);
+    assert_eq!(grid.neighbors8(G::pos(1, 1)).count(), 8);
+
+    assert_eq!(grid.get(G::pos(1, 2)), Some(&b'#'));
+    assert_eq!(grid.get(G::pos(3, 0)), None);
+}
+
+#[test]
+fn grid() {
+    let grid = Grid::<u8>::parse(MAP);
+    check(&grid);
+
+    assert_eq!(grid.rotate_clockwise().to_string(), ".##\n#.#\n...\n");
+    assert_eq!(grid.get(Coord::new(-1, 0)), None);
+}
+
+#[test]
+fn gridu() {
+    let grid = GridU::<u8>::parse(MAP);
+    check(&grid);
+
+    assert_eq!(
+        grid.rotate_counterclockwise().to_string(),
+        GridLike::rotate_counterclockwise(&grid).to_string()
+    );
+}
+
+#[test]
+fn square() {
+    let square = Square::parse("##./#../.#.", '/');
+    check(&square);
+
+    let mut rotated = square.clone();
+    rotated.rotate_inplace();
+    assert_eq!(rotated, square.rotate_clockwise());
+}
+
+#[test]
+fn sub_grid() {
+    let grid = GridU::<u8>::parse(MAP);
+
+    let sub = grid.sub_grid(1, 1, 3, 2);
+    assert_eq!(render(&sub), "..\0\n#.\0\n");
+
+    let mut big = GridU::with_size(4, 4);
+    big.put_sub_grid(2, 2, &grid);
+    assert_eq!(render(&big), "\0\0\0\0\n\0\0\0\0\n\0\0##\n\0\0#.\n");
+
+    let mut square = Square::parse("##./#../.#.", '/');
+    square.put_sub_grid(1, 0, &square.sub_grid(0, 1, 2, 2));
+    assert_eq!(square.to_string(), "##.\n#.#\n.#.\n");
+}
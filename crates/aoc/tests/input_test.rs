@@ -0,0 +1,24 @@
+use aoc::{InputError, normalize_input};
+
+#[test]
+fn input_normalize() {
+    assert_eq!(
+        normalize_input("\u{feff}1x2x3\r\n4x5x6\r\n".to_string()),
+        "1x2x3\n4x5x6\n"
+    );
+    assert_eq!(normalize_input("(()\n".to_string()), "(()\n");
+    assert_eq!(normalize_input("a\rb".to_string()), "a\rb");
+}
+
+#[test]
+fn input_error_at() {
+    let data = "1x2x3\n4xéx6\n";
+
+    let err = InputError::at(data, data.find('é').unwrap(), "expected a number");
+    assert_eq!(err, InputError::new(2, 3, "expected a number"));
+    assert_eq!(err.to_string(), "line 2, column 3: expected a number");
+
+    assert_eq!(InputError::at(data, 0, "").line, 1);
+    assert_eq!(InputError::at(data, data.len(), "").line, 3);
+    assert_eq!(InputError::at(data, data.find('6').unwrap(), "").column, 5);
+}
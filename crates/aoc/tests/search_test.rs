@@ -0,0 +1,151 @@
+use aoc::search;
+use aoc::{Coord, Grid};
+
+const MAZE: &str = "\
+######
+#S..##
+#.#.##
+#...E#
+######
+";
+
+fn find(grid: &Grid<u8>, c: u8) -> Coord {
+    grid.iter().find(|&(_, &v)| v == c).unwrap().0
+}
+
+#[test]
+fn search_bfs() {
+    let search = search::bfs([1], |&n| [n + 1, n * 2], |&n| n == 10);
+
+    assert_eq!(search.goal(), Some(&10));
+    assert_eq!(search.goal_cost(), Some(4));
+    assert_eq!(search.cost(&1), Some(0));
+    assert_eq!(search.path(&10).unwrap(), [1, 2, 4, 5, 10]);
+    assert_eq!(search.path(&1000), None);
+
+    // 2 = 1+1 = 1*2
+    assert_eq!(search.predecessors(&2).collect::<Vec<_>>(), [&1]);
+    assert_eq!(search.predecessors(&10).collect::<Vec<_>>(), [&5]);
+}
+
+#[test]
+fn search_bfs_all() {
+    let search = search::bfs(
+        [0, 10],
+        |&n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n)),
+        |_| false,
+    );
+
+    assert_eq!(search.goal(), None);
+    assert_eq!(search.iter().count(), 11);
+    assert_eq!(search.cost(&5), Some(5));
+    assert_eq!(search.cost(&7), Some(3));
+}
+
+#[test]
+fn search_grid_bfs() {
+    let grid = Grid::<u8>::parse(MAZE);
+    let start = find(&grid, b'S');
+    let end = find(&grid, b'E');
+
+    let search = search::grid_bfs(&grid, [start], |&c| c != b'#', |&pos| pos == end);
+
+    assert_eq!(search.goal_cost(), Some(5));
+    assert_eq!(search.path(&end).unwrap().len(), 6);
+
+    // two shortest paths: through the top row or through the left column
+    assert_eq!(search.on_shortest_paths(search.goals()).len(), 9);
+}
+
+#[test]
+fn search_dijkstra_tiles() {
+    // reindeer maze: moving costs 1, turning costs 1000
+    let grid = Grid::<u8>::parse(MAZE);
+    let start = find(&grid, b'S');
+    let end = find(&grid, b'E');
+
+    let search = search::dijkstra(
+        [(start, Coord::EAST)],
+        |&(pos, dir)| {
+            [
+                ((pos + dir, dir), 1),
+                ((pos, dir.clockwise()), 1000),
+                ((pos, dir.counter_clockwise()), 1000),
+            ]
+            .into_iter()
+            .filter(|&((pos, _), _)| grid[pos] != b'#')
+        },
+        |&(pos, _)| pos == end,
+    );
+
+    assert_eq!(search.goal_cost(), Some(2005));
+    assert_eq!(
+        search.count_on_shortest_paths(search.goals(), |&(pos, _)| pos),
+        9
+    );
+}
+
+#[test]
+fn search_astar() {
+    let grid = Grid::<u8>::parse(MAZE);
+    let start = find(&grid, b'S');
+    let end = find(&grid, b'E');
+
+    let search = search::astar(
+        [start],
+        |&pos| {
+            grid.iter_directions(pos)
+                .filter(|&(_, next)| grid[next] != b'#')
+                .map(|(_, next)| (next, 1))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos.manhattan_distance(end),
+        |&pos| pos == end,
+    );
+
+    assert_eq!(search.goal_cost(), Some(5));
+    assert_eq!(search.path(&end).unwrap().first(), Some(&start));
+}
+
+#[test]
+fn search_bfs01() {
+    // doubling is free, adding 1 costs 1
+    let search = search::bfs01(
+        [3],
+        |&n: &u32| {
+            [(n * 2, 0), (n + 1, 1)]
+                .into_iter()
+                .filter(|&(n, _)| n <= 30)
+        },
+        |&n| n == 25,
+    );
+
+    assert_eq!(search.goal_cost(), Some(1));
+    assert_eq!(search.cost(&12), Some(0));
+    assert_eq!(search.path(&25).unwrap(), [3, 6, 12, 24, 25]);
+}
+
+#[test]
+fn search_free_moves_both_ways() {
+    // 0, 1 and 3 are linked by free moves in both directions, 2 costs 1 from 0 or 1
+    let successors = |n: &u32| match n {
+        0 => vec![(1, 0), (2, 1)],
+        1 => vec![(0, 0), (3, 0), (2, 1)],
+        3 => vec![(1, 0)],
+        _ => vec![],
+    };
+
+    let search = search::bfs01([0], successors, |&n| n == 2);
+    assert_eq!(search.goal_cost(), Some(1));
+    assert_eq!(search.predecessors(&0).count(), 0);
+    assert_eq!(search.path(&1).unwrap(), [0, 1]);
+    assert_eq!(search.path(&2).unwrap(), [0, 2]);
+    assert_eq!(search.on_shortest_paths(&[2]).len(), 3);
+
+    let search = search::dijkstra([0], successors, |&n| n == 2);
+    assert_eq!(search.goal_cost(), Some(1));
+    assert_eq!(search.predecessors(&0).count(), 0);
+    assert_eq!(search.predecessors(&1).collect::<Vec<_>>(), [&0]);
+    assert_eq!(search.path(&3).unwrap(), [0, 1, 3]);
+    assert_eq!(search.on_shortest_paths(&[2, 3]).len(), 4);
+}
@@ -0,0 +1,163 @@
+//! Expected answers files: `<day>.ok` next to the `<day>.in` inputs, or `answer.txt` next to `input.txt`.
+//!
+//! The files contain the answer of part 1 on the first line and the answer of part 2 on the second one,
+//! if any (day 25 has no part 2).
+
+use crate::report::{Reporter, RunRecord, Status, Summary};
+use colored::Colorize;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+/// Return the path of the expected answers of an input file.
+#[must_use]
+pub fn answer_path(input: &Path) -> PathBuf {
+    if input.file_name() == Some("input.txt".as_ref()) {
+        input.with_file_name("answer.txt")
+    } else {
+        input.with_extension("ok")
+    }
+}
+
+/// Read the expected answers, or `None` if the file does not exist.
+#[must_use]
+pub fn read(path: &Path) -> Option<(String, String)> {
+    let ok = std::fs::read_to_string(path).ok()?;
+    let (ok1, ok2) = ok.trim_ascii().split_once('\n').unwrap_or((&ok, ""));
+    Some((ok1.trim_ascii().to_string(), ok2.trim_ascii().to_string()))
+}
+
+/// Write the expected answers.
+///
+/// # Errors
+/// Returns an error if the file cannot be written.
+pub fn write(path: &Path, part1: &str, part2: &str) -> std::io::Result<()> {
+    if part2.is_empty() {
+        std::fs::write(path, format!("{part1}\n"))
+    } else {
+        std::fs::write(path, format!("{part1}\n{part2}\n"))
+    }
+}
+
+/// Record the answers of the runs in the expected answers files.
+///
+/// Missing answers are written. When an answer changes, the new answers are written
+/// only if accepted interactively: without a terminal, they are always rejected.
+/// Only the runs of both parts of the main solution of a day can record answers,
+/// not the alternatives nor the `--part` runs.
+pub struct Recorder {
+    interactive: bool,
+    recorded: u32,
+    accepted: u32,
+    rejected: u32,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            interactive: std::io::stdin().is_terminal(),
+            recorded: 0,
+            accepted: 0,
+            rejected: 0,
+        }
+    }
+}
+
+impl Recorder {
+    /// Ask whether the new answers should replace the expected ones.
+    fn confirm(&self, record: &RunRecord) -> bool {
+        if !self.interactive {
+            return false;
+        }
+
+        eprintln!("{} answers of {} changed:", "⚠️".yellow(), record.name());
+        eprintln!("  expected: {} {}", record.answer1, record.answer2);
+        eprintln!("  found   : {} {}", record.part1, record.part2);
+        eprint!("  accept the new answers? [y/N] ");
+        let _ = std::io::stderr().flush();
+
+        let mut line = String::new();
+        std::io::stdin().lock().read_line(&mut line).is_ok()
+            && line.trim().eq_ignore_ascii_case("y")
+    }
+}
+
+impl Reporter for Recorder {
+    fn record(&mut self, record: &RunRecord) {
+        let input = Path::new(&record.source);
+
+        if record.error.is_some()
+            || record.part.is_some()
+            || record.part1.is_empty()
+            || !input.is_file()
+        {
+            return;
+        }
+
+        let accepted = match record.status {
+            Status::Unchecked if record.alt.is_none() => true,
+            Status::Failed if record.alt.is_none() => {
+                let accepted = self.confirm(record);
+                if !accepted {
+                    self.rejected += 1;
+                }
+                accepted
+            }
+            _ => false,
+        };
+
+        if accepted {
+            let path = answer_path(input);
+
+            match write(&path, &record.part1, &record.part2) {
+                Ok(()) => {
+                    if record.status == Status::Unchecked {
+                        self.recorded += 1;
+                    } else {
+                        self.accepted += 1;
+                    }
+                }
+                Err(err) => eprintln!("failed to write {}: {err}", path.display()),
+            }
+        }
+    }
+
+    fn end(&mut self, _summary: &Summary) {
+        eprintln!(
+            "answers: {} recorded, {} updated, {} rejected",
+            self.recorded, self.accepted, self.rejected
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_path() {
+        assert_eq!(
+            answer_path(Path::new("data/1/2015/3.in")),
+            Path::new("data/1/2015/3.ok")
+        );
+        assert_eq!(
+            answer_path(Path::new("src/year2015/day3/input.txt")),
+            Path::new("src/year2015/day3/answer.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_write() {
+        let path = std::env::temp_dir().join(format!("aor-answers-{}.ok", std::process::id()));
+
+        write(&path, "123", "abc").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "123\nabc\n");
+        assert_eq!(read(&path), Some(("123".to_string(), "abc".to_string())));
+
+        write(&path, "42", "").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "42\n");
+        assert_eq!(read(&path), Some(("42".to_string(), String::new())));
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(read(&path), None);
+    }
+}
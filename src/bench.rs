@@ -0,0 +1,199 @@
+//! Statistical benchmarking of a solution: warmup iterations, then repeated timed runs.
+
+use crate::guard::{Outcome, Phases};
+use std::time::Duration;
+
+/// Distribution of the elapsed times of a benchmark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics of the samples, or `None` if there is none.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        // nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let count = f64::from(u32::try_from(n).unwrap_or(u32::MAX));
+        let mean = secs.iter().sum::<f64>() / count;
+        let variance = if n > 1 {
+            secs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1.)
+        } else {
+            0.
+        };
+
+        Some(Self {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        })
+    }
+}
+
+/// Distributions of the elapsed times of the phases of a benchmark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhaseStats {
+    pub parse: Stats,
+    /// The distribution of part one, if solved.
+    pub part1: Option<Stats>,
+    /// The distribution of part two, if solved.
+    pub part2: Option<Stats>,
+}
+
+impl PhaseStats {
+    /// Compute the statistics of each phase, or `None` if there is no sample.
+    #[must_use]
+    pub fn from_samples(samples: &[Phases]) -> Option<Self> {
+        let part = |part: fn(&Phases) -> Option<Duration>| {
+            let samples: Option<Vec<_>> = samples.iter().map(part).collect();
+            samples.and_then(|samples| Stats::from_samples(&samples))
+        };
+
+        Some(Self {
+            parse: Stats::from_samples(&samples.iter().map(|p| p.parse).collect::<Vec<_>>())?,
+            part1: part(|p| p.part1),
+            part2: part(|p| p.part2),
+        })
+    }
+
+    /// The median elapsed times of the phases.
+    #[must_use]
+    pub fn median(&self) -> Phases {
+        Phases {
+            parse: self.parse.median,
+            part1: self.part1.map(|stats| stats.median),
+            part2: self.part2.map(|stats| stats.median),
+        }
+    }
+}
+
+/// Run `once` `warmup` times without measuring, then `runs` times.
+/// `once` runs the solution and returns its outcome, elapsed time and phases, like [`crate::guard::run_parts`].
+///
+/// Returns the outcome of the last run, the statistics of the timed runs
+/// and the ones of their phases if every run has phases.
+/// The benchmark stops at the first panic or timeout.
+#[must_use]
+pub fn run<F>(
+    mut once: F,
+    warmup: usize,
+    runs: usize,
+) -> (Outcome, Option<Stats>, Option<PhaseStats>)
+where
+    F: FnMut() -> (Outcome, Duration, Option<Phases>),
+{
+    let mut samples = Vec::with_capacity(runs);
+    let mut phases = Some(Vec::with_capacity(runs));
+    let mut last = Outcome::Answers(String::new(), String::new());
+
+    for i in 0..warmup + runs.max(1) {
+        let (outcome, elapsed, phase) = once();
+
+        if !matches!(outcome, Outcome::Answers(..)) {
+            return (outcome, None, None);
+        }
+
+        if i >= warmup {
+            samples.push(elapsed);
+            phases = phases.zip(phase).map(|(mut phases, phase)| {
+                phases.push(phase);
+                phases
+            });
+        }
+        last = outcome;
+    }
+
+    let phases = phases.and_then(|phases| PhaseStats::from_samples(&phases));
+    (last, Stats::from_samples(&samples), phases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guard;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<_> = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.mean.as_micros(), 3000);
+        assert_eq!(stats.stddev.as_micros(), 1581); // sqrt(2.5) ms
+
+        let samples: Vec<_> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(2500));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_run() {
+        let once = || {
+            let (outcome, elapsed) =
+                guard::run(|data| (data.to_string(), String::new()), "42", None);
+            (outcome, elapsed, None)
+        };
+        let (outcome, stats, phases) = run(once, 2, 7);
+        assert_eq!(outcome, Outcome::Answers("42".to_string(), String::new()));
+        assert_eq!(stats.unwrap().runs, 7);
+        assert_eq!(phases, None);
+
+        let once = || {
+            let (outcome, elapsed) = guard::run(|_| panic!("boom"), "", None);
+            (outcome, elapsed, None)
+        };
+        let (outcome, stats, phases) = run(once, 2, 7);
+        assert!(matches!(outcome, Outcome::Panic(_)));
+        assert_eq!(stats, None);
+        assert_eq!(phases, None);
+    }
+
+    #[test]
+    fn test_phase_stats() {
+        let phases = |parse, part2| Phases {
+            parse: Duration::from_millis(parse),
+            part1: None,
+            part2: Some(Duration::from_millis(part2)),
+        };
+        let samples = [phases(3, 10), phases(1, 30), phases(2, 20)];
+        let stats = PhaseStats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.parse.min, Duration::from_millis(1));
+        assert_eq!(stats.part1, None);
+        assert_eq!(stats.part2.unwrap().p95, Duration::from_millis(30));
+        assert_eq!(stats.median(), phases(2, 20));
+
+        assert_eq!(PhaseStats::from_samples(&[]), None);
+    }
+}
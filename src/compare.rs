@@ -0,0 +1,559 @@
+//! Consistency of the answers of the implementations in other languages with the Rust solutions.
+//!
+//! The implementations live next to the Rust ones, as `src/yearXXXX/dayYY/dayYY.<ext>`.
+//! They are built, if needed, into `target/build/yearXXXX` and run with the path of the input,
+//! like `scripts/runall.py` does. Their answers are the lines of their output, except the
+//! `elapsed:` one that gives their own timing.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// How to build and run the implementations in a language.
+///
+/// The commands are split on whitespace, and the placeholders of their words are replaced:
+/// `{src}` by the path of the source file, `{dir}` by its directory, `{name}` by its stem,
+/// `{build}` by the build directory and `{out}` by the path of the built program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Language {
+    pub name: String,
+    pub extension: String,
+    pub build: Option<String>,
+    pub run: String,
+}
+
+impl Language {
+    fn new(name: &str, extension: &str, build: Option<&str>, run: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            extension: extension.to_string(),
+            build: build.map(str::to_string),
+            run: run.to_string(),
+        }
+    }
+}
+
+/// The languages of the repository, with the commands of `scripts/runall.py`.
+#[must_use]
+pub fn languages() -> Vec<Language> {
+    let c = "cc -std=c11 -Wall -Wextra -O3 -DSTANDALONE -I{dir} -o {out} {src}";
+    let cpp = "c++ -std=c++23 -Wall -Wextra -O3 -DSTANDALONE -I{dir} -o {out} {src}";
+
+    vec![
+        Language::new("Python", "py", None, "python3 {src}"),
+        Language::new("C", "c", Some(c), "{out}"),
+        Language::new("C++", "cpp", Some(cpp), "{out}"),
+        Language::new("Go", "go", Some("go build -o {out} {src}"), "{out}"),
+        Language::new(
+            "Java",
+            "java",
+            Some("javac -d {build} {src}"),
+            "java -cp {build} {name}",
+        ),
+        Language::new("C#", "cs", Some("mcs -out:{out} {src}"), "mono {out}"),
+        Language::new("Swift", "swift", Some("swiftc -o {out} {src}"), "{out}"),
+        Language::new("JavaScript", "js", None, "node {src}"),
+        Language::new("Lua", "lua", None, "lua5.4 {src}"),
+        Language::new("Ruby", "rb", None, "ruby {src}"),
+        Language::new("Perl", "pl", None, "perl {src}"),
+        Language::new("Bash", "bash", None, "bash {src}"),
+        Language::new("Tcl", "tcl", None, "tclsh {src}"),
+    ]
+}
+
+/// Override the commands of the languages, or add new ones, with a TOML configuration like:
+///
+/// ```toml
+/// [Python]
+/// run = "target/venv/python/bin/python3 {src}"
+///
+/// [Kotlin]
+/// extension = "kt"
+/// build = "kotlinc {src} -include-runtime -d {out}.jar"
+/// run = "java -jar {out}.jar"
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the configuration is not valid TOML, or if a new language lacks its extension or run command.
+pub fn configure(languages: &mut Vec<Language>, content: &str) -> Result<(), String> {
+    let config: toml::Table = toml::from_str(content).map_err(|err| err.to_string())?;
+
+    for (name, entry) in config {
+        let entry = entry
+            .as_table()
+            .ok_or_else(|| format!("{name}: expected a table"))?;
+        let field = |key: &str| entry.get(key).and_then(toml::Value::as_str);
+
+        let index = languages
+            .iter()
+            .position(|lang| lang.name.eq_ignore_ascii_case(&name))
+            .unwrap_or(languages.len());
+
+        if index == languages.len() {
+            let (Some(extension), Some(run)) = (field("extension"), field("run")) else {
+                return Err(format!(
+                    "{name}: a new language needs an extension and a run command"
+                ));
+            };
+            languages.push(Language::new(&name, extension, None, run));
+        }
+
+        let lang = &mut languages[index];
+        if let Some(extension) = field("extension") {
+            lang.extension = extension.to_string();
+        }
+        if let Some(build) = field("build") {
+            lang.build = (!build.is_empty()).then(|| build.to_string());
+        }
+        if let Some(run) = field("run") {
+            lang.run = run.to_string();
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the implementations of a day in the other languages, in the order of the languages.
+#[must_use]
+pub fn discover<'a>(
+    dir: &Path,
+    day: u8,
+    languages: &'a [Language],
+) -> Vec<(&'a Language, PathBuf)> {
+    languages
+        .iter()
+        .filter(|lang| !lang.name.eq_ignore_ascii_case("rust"))
+        .map(|lang| (lang, dir.join(format!("day{day}.{}", lang.extension))))
+        .filter(|(_, path)| path.is_file())
+        .collect()
+}
+
+/// How an implementation failed to give answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The compiler or the interpreter is not installed.
+    Unavailable(String),
+    /// The build failed.
+    Build(String),
+    /// The program exited with an error.
+    Error(String),
+    Timeout(Duration),
+}
+
+/// The answers and the elapsed time of a run of an implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Output {
+    pub answers: Vec<String>,
+    /// The time given by the program itself with `--elapsed`, otherwise the one of the whole process.
+    pub elapsed: Duration,
+}
+
+/// Build an implementation if needed, and run it with an input.
+///
+/// # Errors
+///
+/// Returns the reason of the failure of the build or of the run.
+pub fn run(
+    lang: &Language,
+    source: &Path,
+    build_dir: &Path,
+    input: &Path,
+    timeout: Option<Duration>,
+) -> Result<Output, Failure> {
+    let stem = source
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let program = build_dir.join(format!("{stem}_{}", lang.extension));
+
+    let expand = |template: &str| -> Vec<String> {
+        template
+            .split_whitespace()
+            .map(|word| {
+                word.replace("{src}", &source.to_string_lossy())
+                    .replace(
+                        "{dir}",
+                        &source.parent().unwrap_or(source).to_string_lossy(),
+                    )
+                    .replace("{name}", &stem)
+                    .replace("{build}", &build_dir.to_string_lossy())
+                    .replace("{out}", &program.to_string_lossy())
+            })
+            .collect()
+    };
+
+    if let Some(build) = &lang.build
+        && !is_fresh(&program, source)
+    {
+        std::fs::create_dir_all(build_dir).map_err(|err| Failure::Build(err.to_string()))?;
+
+        let (status, _) = execute(&expand(build), None).map_err(|failure| match failure {
+            Failure::Error(err) => Failure::Build(err),
+            failure => failure,
+        })?;
+        if !status {
+            return Err(Failure::Build(format!("{} failed", lang.name)));
+        }
+    }
+
+    let mut command = expand(&lang.run);
+    command.push(input.to_string_lossy().to_string());
+    command.push("--elapsed".to_string());
+
+    let start = Instant::now();
+    let (status, stdout) = execute(&command, timeout)?;
+    let elapsed = start.elapsed();
+
+    if !status {
+        return Err(Failure::Error("exit with an error".to_string()));
+    }
+
+    let (answers, internal) = parse_output(&stdout);
+    Ok(Output {
+        answers,
+        elapsed: internal.unwrap_or(elapsed),
+    })
+}
+
+/// Return `true` if the built program is more recent than its source.
+fn is_fresh(program: &Path, source: &Path) -> bool {
+    let modified = |path: &Path| path.metadata().and_then(|m| m.modified()).ok();
+    // javac names the class file after the source
+    let class = program.with_file_name(format!(
+        "{}.class",
+        source.file_stem().unwrap_or_default().to_string_lossy()
+    ));
+
+    modified(program)
+        .or_else(|| modified(&class))
+        .zip(modified(source))
+        .is_some_and(|(program, source)| program >= source)
+}
+
+/// Run a command, and return whether it succeeded with its standard output.
+fn execute(command: &[String], timeout: Option<Duration>) -> Result<(bool, String), Failure> {
+    let Some((program, args)) = command.split_first() else {
+        return Err(Failure::Error("empty command".to_string()));
+    };
+
+    let mut child = Command::new(program)
+        .args(args)
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => Failure::Unavailable(format!("{program} not found")),
+            _ => Failure::Error(format!("{program}: {err}")),
+        })?;
+
+    // read the output aside, not to block a verbose program on a full pipe
+    let mut stdout = child.stdout.take().expect("piped stdout");
+    let reader = std::thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if timeout.is_some_and(|timeout| start.elapsed() > timeout) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(Failure::Timeout(timeout.unwrap_or_default()));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(5)),
+            Err(err) => return Err(Failure::Error(err.to_string())),
+        }
+    };
+
+    Ok((status.success(), reader.join().unwrap_or_default()))
+}
+
+/// Split the output of a program into its answers and its own elapsed time, if any.
+#[must_use]
+pub fn parse_output(stdout: &str) -> (Vec<String>, Option<Duration>) {
+    let mut elapsed = None;
+    let mut answers = Vec::new();
+
+    for line in stdout
+        .lines()
+        .map(str::trim_ascii)
+        .filter(|line| !line.is_empty())
+    {
+        match line.strip_prefix("elapsed:") {
+            Some(duration) => elapsed = parse_duration(duration.trim_ascii()),
+            None => answers.push(line.to_string()),
+        }
+    }
+
+    (answers, elapsed)
+}
+
+/// Parse a duration like `12.5ms`, as printed by `{:?}` or by the scripts.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let units = [
+        ("ns", 1e-9),
+        ("µs", 1e-6),
+        ("us", 1e-6),
+        ("ms", 1e-3),
+        ("s", 1.),
+    ];
+    let (value, scale) = units
+        .iter()
+        .find_map(|(suffix, scale)| Some((s.strip_suffix(suffix)?, *scale)))?;
+    Duration::try_from_secs_f64(value.trim_ascii().parse::<f64>().ok()? * scale).ok()
+}
+
+/// The comparison of an implementation with the Rust solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// Same answers, with the elapsed time.
+    Agree(Duration),
+    /// Different answers, with the elapsed time.
+    Disagree(Duration),
+    Failed(Failure),
+}
+
+impl Verdict {
+    /// Compare the result of an implementation with the answers of the Rust solution.
+    #[must_use]
+    pub fn new(result: Result<Output, Failure>, part1: &str, part2: &str) -> Self {
+        match result {
+            Ok(output) => {
+                let expected: Vec<_> = [part1, part2]
+                    .into_iter()
+                    .flat_map(str::lines)
+                    .map(str::trim_ascii)
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                if output.answers == expected {
+                    Self::Agree(output.elapsed)
+                } else {
+                    Self::Disagree(output.elapsed)
+                }
+            }
+            Err(failure) => Self::Failed(failure),
+        }
+    }
+}
+
+/// Agreement matrix of the implementations: one row per puzzle, one column per language,
+/// with the ratio of the elapsed time to the Rust one.
+#[derive(Default)]
+pub struct Matrix {
+    languages: Vec<String>,
+    /// The elapsed time of the Rust solution and the verdicts of the other languages.
+    rows: BTreeMap<(u16, u8), (Duration, BTreeMap<String, Verdict>)>,
+}
+
+impl Matrix {
+    pub fn add(&mut self, year: u16, day: u8, rust: Duration, lang: &str, verdict: Verdict) {
+        if !self.languages.iter().any(|name| name == lang) {
+            self.languages.push(lang.to_string());
+        }
+        self.rows
+            .entry((year, day))
+            .or_insert_with(|| (rust, BTreeMap::new()))
+            .1
+            .insert(lang.to_string(), verdict);
+    }
+
+    /// Return the number of implementations that do not agree with the Rust solutions.
+    #[must_use]
+    pub fn disagreements(&self) -> usize {
+        self.rows
+            .values()
+            .flat_map(|(_, verdicts)| verdicts.values())
+            .filter(|verdict| matches!(verdict, Verdict::Disagree(_)))
+            .count()
+    }
+
+    fn cell(verdict: &Verdict, rust: Duration) -> String {
+        let ratio = |elapsed: Duration| {
+            if rust.is_zero() {
+                String::new()
+            } else {
+                format!(" {:.1}x", elapsed.as_secs_f64() / rust.as_secs_f64())
+            }
+        };
+
+        match verdict {
+            Verdict::Agree(elapsed) => format!("ok{}", ratio(*elapsed)),
+            Verdict::Disagree(elapsed) => format!("DIFF{}", ratio(*elapsed)),
+            Verdict::Failed(Failure::Unavailable(_)) => "n/a".to_string(),
+            Verdict::Failed(Failure::Build(_)) => "BUILD".to_string(),
+            Verdict::Failed(Failure::Error(_)) => "ERROR".to_string(),
+            Verdict::Failed(Failure::Timeout(_)) => "TIME".to_string(),
+        }
+    }
+
+    /// Render the matrix, with a last row with the number of agreements of each language.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let cells: BTreeMap<_, Vec<_>> = self
+            .rows
+            .iter()
+            .map(|(key, (rust, verdicts))| {
+                let row = self
+                    .languages
+                    .iter()
+                    .map(|lang| {
+                        verdicts
+                            .get(lang)
+                            .map_or_else(String::new, |v| Self::cell(v, *rust))
+                    })
+                    .collect();
+                (key, row)
+            })
+            .collect();
+
+        let totals: Vec<_> = self
+            .languages
+            .iter()
+            .map(|lang| {
+                let (agree, total) = self
+                    .rows
+                    .values()
+                    .filter_map(|(_, verdicts)| verdicts.get(lang))
+                    .fold((0, 0), |(agree, total), verdict| {
+                        (
+                            agree + usize::from(matches!(verdict, Verdict::Agree(_))),
+                            total + 1,
+                        )
+                    });
+                format!("{agree}/{total}")
+            })
+            .collect();
+
+        let widths: Vec<_> = self
+            .languages
+            .iter()
+            .enumerate()
+            .map(|(i, lang)| {
+                cells
+                    .values()
+                    .map(|row| row[i].chars().count())
+                    .chain([lang.chars().count(), totals[i].len(), 5])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let mut text = String::new();
+
+        let mut line = format!("{:<12} | {:^10}", "puzzle", "rust");
+        for (lang, width) in self.languages.iter().zip(&widths) {
+            let _ = write!(line, " | {lang:^width$}");
+        }
+        let _ = writeln!(text, "{}", line.trim_end());
+
+        for ((year, day), row) in &cells {
+            let rust = format!("{:.3?}", self.rows[&(*year, *day)].0);
+            let mut line = format!("{:<12} | {rust:>10}", format!("{year} day {day}"));
+            for (cell, width) in row.iter().zip(&widths) {
+                let _ = write!(line, " | {cell:^width$}");
+            }
+            let _ = writeln!(text, "{}", line.trim_end());
+        }
+
+        let mut line = format!("{:<12} | {:10}", "agree", "");
+        for (total, width) in totals.iter().zip(&widths) {
+            let _ = write!(line, " | {total:^width$}");
+        }
+        let _ = writeln!(text, "{}", line.trim_end());
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output() {
+        assert_eq!(
+            parse_output("3318604\n4975039\nelapsed: 1.25ms\n"),
+            (
+                vec!["3318604".to_string(), "4975039".to_string()],
+                Some(Duration::from_micros(1250))
+            )
+        );
+        assert_eq!(parse_output("42\n\n"), (vec!["42".to_string()], None));
+        assert_eq!(parse_duration("12µs"), Some(Duration::from_micros(12)));
+        assert_eq!(parse_duration("2s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("soon"), None);
+    }
+
+    #[test]
+    fn test_configure() {
+        let mut langs = languages();
+        let config = r#"
+[python]
+run = "pypy3 {src}"
+
+[Kotlin]
+extension = "kt"
+build = "kotlinc {src} -d {out}.jar"
+run = "java -jar {out}.jar"
+"#;
+        configure(&mut langs, config).unwrap();
+
+        assert_eq!(langs[0].run, "pypy3 {src}");
+        assert_eq!(langs.last().unwrap().extension, "kt");
+        assert!(configure(&mut langs, "[Zig]\nrun = \"zig run {src}\"").is_err());
+    }
+
+    #[test]
+    fn test_matrix() {
+        let mut matrix = Matrix::default();
+        let ms = Duration::from_millis;
+        let output = |answers: &[&str], elapsed| {
+            Ok(Output {
+                answers: answers.iter().map(ToString::to_string).collect(),
+                elapsed,
+            })
+        };
+
+        matrix.add(
+            2019,
+            1,
+            ms(2),
+            "Python",
+            Verdict::new(output(&["1", "2"], ms(30)), "1", "2"),
+        );
+        matrix.add(
+            2019,
+            1,
+            ms(2),
+            "C",
+            Verdict::new(output(&["1", "3"], ms(1)), "1", "2"),
+        );
+        matrix.add(
+            2019,
+            2,
+            ms(4),
+            "Python",
+            Verdict::new(Err(Failure::Unavailable("python3".to_string())), "5", ""),
+        );
+
+        assert_eq!(matrix.disagreements(), 1);
+        assert_eq!(
+            matrix.render(),
+            "\
+puzzle       |    rust    |  Python  |     C
+2019 day 1   |    2.000ms | ok 15.0x | DIFF 0.5x
+2019 day 2   |    4.000ms |   n/a    |
+agree        |            |   1/2    |    0/1
+"
+        );
+    }
+}
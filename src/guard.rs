@@ -0,0 +1,310 @@
+//! Run a solution in its own thread, with a wall-clock timeout and panic capture.
+
+use crate::Solution;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Once, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Name of the threads that run the solutions, used to recognize them in the panic hook.
+const SOLVER_THREAD: &str = "aor-solver";
+
+/// Stack size of the solver threads, some solutions are deeply recursive.
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Number of solver threads that timed out and are still running.
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// States of a solver thread, shared with its caller.
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const TIMED_OUT: u8 = 2;
+
+/// Return the number of solutions that timed out and whose threads are still running.
+///
+/// They keep a core busy, so the elapsed times measured meanwhile are unreliable.
+#[must_use]
+pub fn abandoned() -> usize {
+    ABANDONED.load(Ordering::SeqCst)
+}
+
+/// The way a guarded run ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The solution returned its two answers.
+    Answers(String, String),
+    /// The solution panicked, with the message and the location.
+    Panic(String),
+    /// The solution did not finish in time. Its thread is left running.
+    Timeout(Duration),
+}
+
+/// Install a panic hook that silently records the panics of the solver threads
+/// and defers to the previous hook for all other threads.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() == Some(SOLVER_THREAD) {
+                let msg = info.payload_as_str().unwrap_or("Box<dyn Any>");
+                let msg = info
+                    .location()
+                    .map_or_else(|| msg.to_string(), |loc| format!("{msg} at {loc}"));
+
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(msg));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Run `task` with `data` in a dedicated thread.
+///
+/// Returns the result of the task, or the outcome of its panic or timeout, and the elapsed time of the call.
+///
+/// # Panics
+/// If the solver thread cannot be spawned.
+fn spawn<T, F>(task: F, data: &str, timeout: Option<Duration>) -> (Result<T, Outcome>, Duration)
+where
+    T: Send + 'static,
+    F: FnOnce(&str) -> T + Send + 'static,
+{
+    install_hook();
+
+    let (tx, rx) = mpsc::channel();
+    let data = data.to_string();
+    let state = Arc::new(AtomicU8::new(RUNNING));
+    let thread_state = Arc::clone(&state);
+
+    thread::Builder::new()
+        .name(SOLVER_THREAD.to_string())
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let instant = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| task(&data)));
+            let elapsed = instant.elapsed();
+
+            let result = result.map_err(|_| {
+                Outcome::Panic(
+                    LAST_PANIC
+                        .with(|last| last.borrow_mut().take())
+                        .unwrap_or_default(),
+                )
+            });
+
+            // the receiver is gone if we timed out
+            let _ = tx.send((result, elapsed));
+
+            if thread_state
+                .compare_exchange(RUNNING, FINISHED, Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+            {
+                ABANDONED.fetch_sub(1, Ordering::SeqCst);
+            }
+        })
+        .expect("failed to spawn the solver thread");
+
+    timeout.map_or_else(
+        || {
+            rx.recv()
+                .unwrap_or_else(|_| (Err(Outcome::Panic(String::new())), Duration::ZERO))
+        },
+        |timeout| {
+            rx.recv_timeout(timeout).unwrap_or_else(|_| {
+                // counted before the thread can finish, and uncounted if it already has
+                ABANDONED.fetch_add(1, Ordering::SeqCst);
+                if state
+                    .compare_exchange(RUNNING, TIMED_OUT, Ordering::SeqCst, Ordering::SeqCst)
+                    .is_err()
+                {
+                    ABANDONED.fetch_sub(1, Ordering::SeqCst);
+                }
+                (Err(Outcome::Timeout(timeout)), timeout)
+            })
+        },
+    )
+}
+
+/// Run `solve` with `data` in a dedicated thread.
+///
+/// Returns the outcome and the elapsed time of the call.
+///
+/// # Panics
+/// If the solver thread cannot be spawned.
+#[must_use]
+pub fn run(
+    solve: fn(&str) -> (String, String),
+    data: &str,
+    timeout: Option<Duration>,
+) -> (Outcome, Duration) {
+    let (result, elapsed) = spawn(solve, data, timeout);
+
+    let outcome = match result {
+        Ok((part1, part2)) => Outcome::Answers(part1, part2),
+        Err(outcome) => outcome,
+    };
+
+    (outcome, elapsed)
+}
+
+/// The elapsed times of the phases of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Phases {
+    pub parse: Duration,
+    /// The elapsed time of part one, if solved.
+    pub part1: Option<Duration>,
+    /// The elapsed time of part two, if solved.
+    pub part2: Option<Duration>,
+}
+
+/// Run the parts of a solution with `data` in a dedicated thread: only `part` if any, otherwise both.
+///
+/// With a `parse` callback, the input is parsed once and each part is timed separately.
+/// Otherwise, the whole solution runs and the answer of the other part is dropped.
+///
+/// Returns the outcome, the elapsed time of the call and the ones of the phases.
+///
+/// # Panics
+/// If the solver thread cannot be spawned.
+#[must_use]
+pub fn run_parts(
+    sol: &Solution,
+    part: Option<u8>,
+    data: &str,
+    timeout: Option<Duration>,
+) -> (Outcome, Duration, Option<Phases>) {
+    let (solve, parse) = (sol.solve, sol.parse);
+    let selected = move |p: u8| part.is_none_or(|part| part == p);
+
+    let task = move |data: &str| {
+        let Some(parse) = parse else {
+            let (part1, part2) = solve(data);
+            return (
+                if selected(1) { part1 } else { String::new() },
+                if selected(2) { part2 } else { String::new() },
+                None,
+            );
+        };
+
+        let instant = Instant::now();
+        let puzzle = parse(data);
+        let parse = instant.elapsed();
+
+        let timed = |p: u8, solve: &dyn Fn() -> String| {
+            if !selected(p) {
+                return (String::new(), None);
+            }
+            let instant = Instant::now();
+            let answer = solve();
+            (answer, Some(instant.elapsed()))
+        };
+
+        let (part1, elapsed1) = timed(1, &|| puzzle.part1());
+        let (part2, elapsed2) = timed(2, &|| puzzle.part2());
+
+        let phases = Phases {
+            parse,
+            part1: elapsed1,
+            part2: elapsed2,
+        };
+
+        (part1, part2, Some(phases))
+    };
+
+    match spawn(task, data, timeout) {
+        (Ok((part1, part2, phases)), elapsed) => (Outcome::Answers(part1, part2), elapsed, phases),
+        (Err(outcome), elapsed) => (outcome, elapsed, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let (outcome, _) = run(|data| (data.len().to_string(), String::new()), "abc", None);
+        assert_eq!(outcome, Outcome::Answers("3".to_string(), String::new()));
+    }
+
+    #[test]
+    fn test_panic() {
+        let (outcome, _) = run(|_| panic!("opcode 99 not implemented"), "", None);
+        let Outcome::Panic(msg) = outcome else {
+            panic!("expected a panic outcome");
+        };
+        assert!(msg.starts_with("opcode 99 not implemented at src/guard.rs:"));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let timeout = Duration::from_millis(10);
+        let (outcome, elapsed) = run(
+            |_| {
+                thread::sleep(Duration::from_millis(200));
+                (String::new(), String::new())
+            },
+            "",
+            Some(timeout),
+        );
+        assert_eq!(outcome, Outcome::Timeout(timeout));
+        assert_eq!(elapsed, timeout);
+
+        // the solver thread keeps running until its end
+        assert_eq!(abandoned(), 1);
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(abandoned(), 0);
+    }
+
+    #[test]
+    fn test_run_parts() {
+        let sol = Solution {
+            year: 2015,
+            day: 1,
+            alt: None,
+            title: "Not Quite Lisp",
+            tags: &[],
+            slow: false,
+            answers: ("usize", "String"),
+            languages: &["Rust"],
+            solve: |data| (data.len().to_string(), data.to_uppercase()),
+            parse: Some(|data| {
+                Box::new(crate::Parsed {
+                    puzzle: data.to_string(),
+                    part1: |puzzle| puzzle.len().to_string(),
+                    part2: |puzzle| puzzle.to_uppercase(),
+                })
+            }),
+            validate: None,
+            samples: &[],
+            main: || (),
+        };
+
+        let (outcome, _, phases) = run_parts(&sol, None, "abc", None);
+        assert_eq!(
+            outcome,
+            Outcome::Answers("3".to_string(), "ABC".to_string())
+        );
+        let phases = phases.unwrap();
+        assert!(phases.part1.is_some() && phases.part2.is_some());
+
+        let (outcome, _, phases) = run_parts(&sol, Some(2), "abc", None);
+        assert_eq!(outcome, Outcome::Answers(String::new(), "ABC".to_string()));
+        assert_eq!(phases.unwrap().part1, None);
+
+        let sol = Solution { parse: None, ..sol };
+        let (outcome, _, phases) = run_parts(&sol, Some(1), "abc", None);
+        assert_eq!(outcome, Outcome::Answers("3".to_string(), String::new()));
+        assert_eq!(phases, None);
+    }
+}
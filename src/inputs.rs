@@ -0,0 +1,477 @@
+//! Content-addressed store of the puzzle inputs, with their metadata.
+//!
+//! The inputs are stored once, as `objects/<xx>/<digest>`, keyed by the same SHA-256 digest as the timings
+//! databases. The index `index.json` tells for each of them the user, the puzzle, the expected answers and
+//! where it was imported from. The store is filled from directories or tarballs of input files, without network.
+
+use crate::answers;
+use crate::timings::{digest, timestamp};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// The metadata of an input of the store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoredInput {
+    pub digest: String,
+    pub user: String,
+    pub year: u16,
+    pub day: u8,
+    pub answer1: String,
+    pub answer2: String,
+    /// The file it was imported from.
+    pub source: String,
+    /// When it was imported, in seconds since the Unix epoch.
+    pub imported: u64,
+}
+
+impl StoredInput {
+    /// The first 12 characters of the digest, or all of it if the index has a shorter one.
+    #[must_use]
+    pub fn short_digest(&self) -> &str {
+        self.digest.get(..12).unwrap_or(&self.digest)
+    }
+}
+
+/// An input file found in an imported directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found {
+    pub path: PathBuf,
+    pub user: Option<String>,
+    pub year: u16,
+    pub day: u8,
+}
+
+/// The number of inputs added, updated and unchanged by an import.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Imported {
+    pub added: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+/// A problem found by [`InputStore::verify`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// The object of the input is missing.
+    Missing,
+    /// The object of the input does not match its digest.
+    Corrupted(String),
+    /// The same input is also recorded for another puzzle.
+    Conflict(u16, u8),
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing object"),
+            Self::Corrupted(digest) => write!(f, "corrupted object, digest {digest}"),
+            Self::Conflict(year, day) => write!(f, "same input as {year} day {day}"),
+        }
+    }
+}
+
+/// The store of the inputs, with its index loaded in memory.
+pub struct InputStore {
+    dir: PathBuf,
+    inputs: Vec<StoredInput>,
+}
+
+impl InputStore {
+    /// Opens the store in `dir`, empty if it does not exist yet.
+    ///
+    /// # Errors
+    /// Returns an error if the index cannot be read or is not valid.
+    pub fn open(dir: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut inputs = Vec::new();
+        let path = dir.join("index.json");
+
+        if path.is_file() {
+            let doc: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+
+            let field = |v: &serde_json::Value, name: &str| -> Result<u64, Box<dyn Error>> {
+                v[name]
+                    .as_u64()
+                    .ok_or_else(|| format!("missing field {name}").into())
+            };
+            let text = |v: &serde_json::Value, name: &str| -> String {
+                v[name].as_str().unwrap_or_default().to_string()
+            };
+
+            for v in doc["inputs"].as_array().into_iter().flatten() {
+                inputs.push(StoredInput {
+                    digest: text(v, "digest"),
+                    user: text(v, "user"),
+                    year: u16::try_from(field(v, "year")?)?,
+                    day: u8::try_from(field(v, "day")?)?,
+                    answer1: text(v, "answer1"),
+                    answer2: text(v, "answer2"),
+                    source: text(v, "source"),
+                    imported: field(v, "imported")?,
+                });
+            }
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            inputs,
+        })
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let inputs: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| {
+                serde_json::json!({
+                    "digest": input.digest,
+                    "user": input.user,
+                    "year": input.year,
+                    "day": input.day,
+                    "answer1": input.answer1,
+                    "answer2": input.answer2,
+                    "source": input.source,
+                    "imported": input.imported,
+                })
+            })
+            .collect();
+
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(
+            self.dir.join("index.json"),
+            serde_json::to_string_pretty(&serde_json::json!({ "inputs": inputs }))?,
+        )?;
+        Ok(())
+    }
+
+    fn object_path(&self, digest: &str) -> PathBuf {
+        self.dir
+            .join("objects")
+            .join(&digest[..2.min(digest.len())])
+            .join(digest)
+    }
+
+    /// The inputs of the store, by year, day and user.
+    #[must_use]
+    pub fn inputs(&self) -> Vec<&StoredInput> {
+        let mut inputs: Vec<_> = self.inputs.iter().collect();
+        inputs.sort_by(|a, b| (a.year, a.day, &a.user).cmp(&(b.year, b.day, &b.user)));
+        inputs
+    }
+
+    /// The input of a puzzle, of the first user in the order of the names.
+    #[must_use]
+    pub fn find(&self, year: u16, day: u8) -> Option<&StoredInput> {
+        self.inputs()
+            .into_iter()
+            .find(|input| input.year == year && input.day == day)
+    }
+
+    /// Read the content of an input.
+    ///
+    /// # Errors
+    /// Returns an error if its object cannot be read.
+    pub fn read(&self, input: &StoredInput) -> std::io::Result<String> {
+        std::fs::read_to_string(self.object_path(&input.digest))
+    }
+
+    /// Add an input with its metadata, or update its answers and source.
+    ///
+    /// Returns `None` if the input was unchanged, `Some(true)` if it was added.
+    fn add(&mut self, data: &str, input: StoredInput) -> Result<Option<bool>, Box<dyn Error>> {
+        let path = self.object_path(&input.digest);
+        if !path.is_file() {
+            std::fs::create_dir_all(path.parent().unwrap_or(&self.dir))?;
+            std::fs::write(&path, data)?;
+        }
+
+        let existing = self.inputs.iter_mut().find(|stored| {
+            (&stored.digest, &stored.user, stored.year, stored.day)
+                == (&input.digest, &input.user, input.year, input.day)
+        });
+
+        match existing {
+            None => {
+                self.inputs.push(input);
+                Ok(Some(true))
+            }
+            Some(stored)
+                if (&stored.answer1, &stored.answer2, &stored.source)
+                    == (&input.answer1, &input.answer2, &input.source) =>
+            {
+                Ok(None)
+            }
+            Some(stored) => {
+                *stored = StoredInput {
+                    imported: stored.imported,
+                    ..input
+                };
+                Ok(Some(false))
+            }
+        }
+    }
+
+    /// Import the inputs of a directory or of a tarball, with their answers if any.
+    ///
+    /// The user is `user` if given, otherwise the name of the directory above the year one.
+    ///
+    /// # Errors
+    /// Returns an error if the tarball cannot be extracted, or if the store cannot be written.
+    pub fn import(&mut self, path: &Path, user: Option<&str>) -> Result<Imported, Box<dyn Error>> {
+        if path.is_dir() {
+            return self.import_dir(path, &path.display().to_string(), user);
+        }
+
+        // the tar command knows the compressions
+        let dir = std::env::temp_dir().join(format!("aor-import-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+
+        let status = std::process::Command::new("tar")
+            .arg("-xf")
+            .arg(path)
+            .arg("-C")
+            .arg(&dir)
+            .status();
+
+        let imported = match status {
+            Ok(status) if status.success() => {
+                self.import_dir(&dir, &format!("{}:", path.display()), user)
+            }
+            Ok(_) => Err(format!("cannot extract {}", path.display()).into()),
+            Err(err) => Err(format!("tar: {err}").into()),
+        };
+
+        let _ = std::fs::remove_dir_all(&dir);
+        imported
+    }
+
+    fn import_dir(
+        &mut self,
+        dir: &Path,
+        source: &str,
+        user: Option<&str>,
+    ) -> Result<Imported, Box<dyn Error>> {
+        let mut imported = Imported::default();
+        let now = timestamp()?;
+
+        for found in scan(dir) {
+            let data = std::fs::read_to_string(&found.path)?;
+            let (answer1, answer2) =
+                answers::read(&answers::answer_path(&found.path)).unwrap_or_default();
+            let relative = found.path.strip_prefix(dir).unwrap_or(&found.path);
+
+            let input = StoredInput {
+                digest: digest(&data),
+                user: user.map(str::to_string).or(found.user).unwrap_or_default(),
+                year: found.year,
+                day: found.day,
+                answer1,
+                answer2,
+                source: if source.ends_with(':') {
+                    format!("{source}{}", relative.display())
+                } else {
+                    Path::new(source).join(relative).display().to_string()
+                },
+                imported: now,
+            };
+
+            match self.add(&data, input)? {
+                Some(true) => imported.added += 1,
+                Some(false) => imported.updated += 1,
+                None => imported.unchanged += 1,
+            }
+        }
+
+        self.save()?;
+        Ok(imported)
+    }
+
+    /// Check the objects against their digest, and the inputs recorded for several puzzles.
+    #[must_use]
+    pub fn verify(&self) -> Vec<(&StoredInput, Problem)> {
+        let mut problems = Vec::new();
+
+        for input in self.inputs() {
+            match self.read(input) {
+                Err(_) => problems.push((input, Problem::Missing)),
+                Ok(data) if digest(&data) != input.digest => {
+                    problems.push((input, Problem::Corrupted(digest(&data))));
+                }
+                Ok(_) => {
+                    if let Some(other) = self.inputs.iter().find(|other| {
+                        other.digest == input.digest
+                            && (other.year != input.year || other.day != input.day)
+                    }) {
+                        problems.push((input, Problem::Conflict(other.year, other.day)));
+                    }
+                }
+            }
+        }
+
+        problems
+    }
+}
+
+/// Parse a year directory name, like `2015` or `year2015`.
+fn parse_year(name: &str) -> Option<u16> {
+    name.strip_prefix("year")
+        .unwrap_or(name)
+        .parse()
+        .ok()
+        .filter(|year| *year >= 2015)
+}
+
+/// Parse the day of an input file, like `1.in`, `day1.txt` or `day1/input.txt`.
+fn parse_day(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+
+    let day = if name == "input.txt" {
+        path.parent()?.file_name()?.to_str()?.strip_prefix("day")?
+    } else {
+        let (stem, ext) = name.rsplit_once('.')?;
+        if ext != "in" && ext != "txt" {
+            return None;
+        }
+        stem.strip_prefix("day").unwrap_or(stem)
+    };
+
+    day.parse().ok().filter(|day| (1..=25).contains(day))
+}
+
+/// Find the input files of a directory tree.
+///
+/// The layouts are the ones of `input/`, `data/` and of the source tree: `[<user>/]<year>/<day>.in`, `[<user>/]<year>/day<day>.txt` or `[<user>/]year<year>/day<day>/input.txt`.
+#[must_use]
+pub fn scan(dir: &Path) -> Vec<Found> {
+    let mut found = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(path) = stack.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                stack.extend(entries.filter_map(Result::ok).map(|entry| entry.path()));
+            }
+            continue;
+        }
+
+        let Some(day) = parse_day(&path) else {
+            continue;
+        };
+
+        let components: Vec<_> = path
+            .strip_prefix(dir)
+            .unwrap_or(&path)
+            .iter()
+            .filter_map(|c| c.to_str())
+            .collect();
+
+        // the nearest year directory, and the user directory above it
+        let Some(index) = components
+            .iter()
+            .rposition(|name| parse_year(name).is_some())
+        else {
+            continue;
+        };
+
+        found.push(Found {
+            year: parse_year(components[index]).unwrap_or_default(),
+            day,
+            user: index
+                .checked_sub(1)
+                .map(|i| components[i].to_string())
+                .filter(|user| !user.starts_with('.')),
+            path,
+        });
+    }
+
+    found.sort_by(|a, b| a.path.cmp(&b.path));
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        let dir = std::env::temp_dir().join(format!("aor-scan-{}", std::process::id()));
+        for file in [
+            "alice/2015/1.in",
+            "alice/2015/1.ok",
+            "2016/day2.txt",
+            "year2017/day3/input.txt",
+            "year2017/day3/day3.rs",
+            "notes/26.in",
+        ] {
+            let path = dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "data").unwrap();
+        }
+
+        let found: Vec<_> = scan(&dir)
+            .into_iter()
+            .map(|f| (f.user, f.year, f.day))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (None, 2016, 2),
+                (Some("alice".to_string()), 2015, 1),
+                (None, 2017, 3)
+            ]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_store() {
+        let dir = std::env::temp_dir().join(format!("aor-store-{}", std::process::id()));
+        let data_dir = dir.join("data");
+        std::fs::create_dir_all(data_dir.join("alice/2015")).unwrap();
+        std::fs::write(data_dir.join("alice/2015/1.in"), "(()\n").unwrap();
+        std::fs::write(data_dir.join("alice/2015/1.ok"), "1\n3\n").unwrap();
+
+        let store_dir = dir.join("store");
+        let mut store = InputStore::open(&store_dir).unwrap();
+        let imported = store.import(&data_dir, None).unwrap();
+        assert_eq!(imported.added, 1);
+
+        // reopened from the index
+        let mut store = InputStore::open(&store_dir).unwrap();
+        let input = store.find(2015, 1).unwrap().clone();
+        assert_eq!(input.user, "alice");
+        assert_eq!((input.answer1.as_str(), input.answer2.as_str()), ("1", "3"));
+        assert_eq!(store.read(&input).unwrap(), "(()\n");
+        assert!(store.verify().is_empty());
+
+        // importing again changes nothing, another user adds an input
+        let imported = store.import(&data_dir, Some("bob")).unwrap();
+        assert_eq!((imported.added, imported.unchanged), (1, 0));
+        assert_eq!(store.import(&data_dir, None).unwrap().unchanged, 1);
+
+        // from a tarball
+        let tarball = dir.join("inputs.tar.gz");
+        let status = std::process::Command::new("tar")
+            .arg("-czf")
+            .arg(&tarball)
+            .arg("-C")
+            .arg(&dir)
+            .arg("data")
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let imported = store.import(&tarball, Some("carol")).unwrap();
+        assert_eq!(imported.added, 1);
+        let carol = store
+            .inputs()
+            .into_iter()
+            .find(|i| i.user == "carol")
+            .unwrap();
+        assert!(carol.source.ends_with("inputs.tar.gz:data/alice/2015/1.in"));
+
+        std::fs::write(store.object_path(&input.digest), "())\n").unwrap();
+        assert!(matches!(store.verify()[0], (_, Problem::Corrupted(_))));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
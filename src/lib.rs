@@ -1,135 +1,192 @@
 #[cfg(feature = "timingsdb")]
 pub mod rundb;
 
+pub mod answers;
+pub mod bench;
+pub mod compare;
+pub mod guard;
+pub mod inputs;
+pub mod manifest;
+pub mod markdown;
+pub mod report;
+pub mod scaffold;
+pub mod serve;
+pub mod timings;
+pub mod validate;
+pub mod watch;
+
 use itertools::Itertools;
-use std::iter::empty;
 
 /// Get the array of all available solutions.
 #[must_use]
 pub fn solutions(year: Option<u16>, day: Option<u8>, alt: &Option<String>) -> Vec<Solution> {
-    let sols = empty()
-        .chain(year2015())
-        .chain(year2016())
-        .chain(year2017())
-        .chain(year2018())
-        .chain(year2019())
-        .chain(year2020())
-        .chain(year2021())
-        .chain(year2022())
-        .chain(year2023())
-        .chain(year2024())
-        .chain(year2025());
-
-    sols.filter(|sol| year.is_none_or(|x| x == sol.year))
+    registry()
+        .filter(|sol| year.is_none_or(|x| x == sol.year))
         .filter(|sol| day.is_none_or(|x| x == sol.day))
         .filter(|sol| alt == &Some("*".to_string()) || alt == &sol.alt)
         .sorted_unstable_by_key(|sol| (sol.year, sol.day, sol.alt.is_some()))
         .collect()
 }
 
-/// A solution for given year and day.
-/// Offer two callbacks:
+/// A category of puzzle or of technique used by a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Grid,
+    Graph,
+    Vm,
+    Z3,
+    Rayon,
+}
+
+impl Tag {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Grid => "grid",
+            Self::Graph => "graph",
+            Self::Vm => "vm",
+            Self::Z3 => "z3",
+            Self::Rayon => "rayon",
+        }
+    }
+}
+
+impl std::str::FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "grid" => Ok(Self::Grid),
+            "graph" => Ok(Self::Graph),
+            "vm" => Ok(Self::Vm),
+            "z3" => Ok(Self::Z3),
+            "rayon" => Ok(Self::Rayon),
+            _ => Err(format!(
+                "unknown tag: {s} (expected grid, graph, vm, z3 or rayon)"
+            )),
+        }
+    }
+}
+
+/// A solution for given year and day, with its metadata.
+/// Offer four callbacks:
 ///  - `solve` that takes the puzzle input and returns part one and two
+///  - `parse` that takes the puzzle input and returns a [`Puzzle`] able to solve each part separately,
+///    for the days with the `Puzzle::new`, `part1` and `part2` public entry points
+///  - `validate` that checks the puzzle input before solving, for the days with a public `validate` function
 ///  - `main` that acts like a standalone program for the given day
+///
+/// The `samples` of the puzzle description come from the `samples.toml` manifest of the day, if any.
+///
+/// The registry is generated by `build.rs` from the `src/yearXXXX/dayYY[_alt]` tree:
+/// the title comes from the `//! [Day N: title](url)` header, the tags are inferred from the source code
+/// and corrected by a `// tags: graph,-vm` comment, that also marks the slow solutions with `slow`,
+/// and the languages come from the year README.
 #[derive(Clone)]
 pub struct Solution {
     pub year: u16,
     pub day: u8,
     pub alt: Option<String>,
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    pub slow: bool,
+    /// The types of the answers of part one and two.
+    pub answers: (&'static str, &'static str),
+    pub languages: &'static [&'static str],
     pub solve: fn(&str) -> (String, String),
+    pub parse: Option<Parse>,
+    pub validate: Option<Validate>,
+    pub samples: &'static [Sample],
     pub main: fn() -> (),
 }
 
-macro_rules! make_year {
-    ($year:tt $($day:tt),*) => {
-        mod $year {
-            $(pub mod $day { pub mod $day; })*
-        }
+/// Parse the puzzle input of a solution.
+pub type Parse = fn(&str) -> Box<dyn Puzzle + '_>;
+
+/// A sample of the puzzle description, with its expected answers.
+#[derive(Clone, Copy, Debug)]
+pub struct Sample {
+    /// The name of the sample file, without the `.txt` extension.
+    pub name: &'static str,
+    pub data: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
 
-        #[must_use]
-        pub fn $year() -> Vec<Solution> {
-            vec![$({
-                let year = stringify!($year)[4..].parse().unwrap();
-                let day = &stringify!($day)[3..];
-
-                let (day, alt) = day
-                    .split_once('_')
-                    .map_or((day, None), |(day, alt)| (day, Some(alt.to_string())));
-                let day = day.parse().unwrap();
-
-                let solve = |data: &str| {
-                    use crate::$year::$day::$day::solve;
-                    let (part1, part2) = solve(data);
-                    (part1.to_string(), part2.to_string())
-                };
-
-                let main = || {
-                    use crate::$year::$day::$day::main;
-                    main();
-                };
-
-                Solution { year, day, alt, solve, main }
-            },)*]
-        }
+/// Check the puzzle input of a solution.
+pub type Validate = fn(&str) -> Result<(), aoc::InputError>;
+
+/// The parsed input of a solution, whose parts can be solved and timed separately.
+pub trait Puzzle {
+    fn part1(&self) -> String;
+    fn part2(&self) -> String;
+}
+
+/// Adapter from the `Puzzle` struct of a day to the [`Puzzle`] trait.
+struct Parsed<P> {
+    puzzle: P,
+    part1: fn(&P) -> String,
+    part2: fn(&P) -> String,
+}
+
+impl<P> Puzzle for Parsed<P> {
+    fn part1(&self) -> String {
+        (self.part1)(&self.puzzle)
+    }
+
+    fn part2(&self) -> String {
+        (self.part2)(&self.puzzle)
     }
 }
 
-make_year!(year2015
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25
-);
-
-make_year!(year2016
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day5_orig,day14_orig
-);
-
-make_year!(year2017
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25
-);
-
-make_year!(year2018
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day9_c,day23_z3
-);
-
-make_year!(year2019
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25
-);
-
-make_year!(year2020
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25
-);
-
-make_year!(year2021
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day19_orig
-);
-
-make_year!(year2022
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day13_pest,day16_orig,day19_orig
-);
-
-make_year!(year2023
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day23_orig,day24_z3
-);
-
-make_year!(year2024
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12,day13,
-    day14,day15,day16,day17,day18,day19,day20,day21,day22,day23,day24,day25,
-    day6_orig,day13_z3
-);
-
-make_year!(year2025
-    day1,day2,day3,day4,day5,day6,day7,day8,day9,day10,day11,day12
-);
+/// Build the descriptor of a solution, for the registry generated by `build.rs`.
+macro_rules! solution {
+    ($year:ident :: $day:ident, $y:literal, $d:literal, $alt:expr, $title:literal,
+     [$($tag:ident),*], $slow:literal, ($answer1:literal, $answer2:literal), [$($lang:literal),*]
+     $(, parse: $puzzle:ident)? $(, validate: $validate:ident)? $(, samples: $samples:ident)?) => {
+        Solution {
+            year: $y,
+            day: $d,
+            alt: $alt.map(str::to_string),
+            title: $title,
+            tags: &[$(Tag::$tag),*],
+            slow: $slow,
+            answers: ($answer1, $answer2),
+            languages: &[$($lang),*],
+            solve: |data| {
+                let (part1, part2) = crate::$year::$day::$day::solve(data);
+                (part1.to_string(), part2.to_string())
+            },
+            parse: solution!(@parse $year::$day $(, $puzzle)?),
+            validate: solution!(@validate $year::$day $(, $validate)?),
+            samples: solution!(@samples $($samples)?),
+            main: || crate::$year::$day::$day::main(),
+        }
+    };
+    (@parse $year:ident :: $day:ident) => {
+        None
+    };
+    (@parse $year:ident :: $day:ident, $puzzle:ident) => {
+        Some(|data| {
+            Box::new(Parsed {
+                puzzle: crate::$year::$day::$day::$puzzle::new(data),
+                part1: |puzzle| puzzle.part1().to_string(),
+                part2: |puzzle| puzzle.part2().to_string(),
+            })
+        })
+    };
+    (@validate $year:ident :: $day:ident) => {
+        None
+    };
+    (@validate $year:ident :: $day:ident, $validate:ident) => {
+        Some(crate::$year::$day::$day::$validate)
+    };
+    (@samples) => {
+        &[]
+    };
+    (@samples $samples:ident) => {
+        $samples
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
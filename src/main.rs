@@ -1,13 +1,201 @@
-use aor::{Solution, solutions};
+use aoc::{Args, Command, Opt, Spec};
+use aor::answers::{self, Recorder};
+use aor::compare;
+use aor::guard::Outcome;
+use aor::inputs::InputStore;
+use aor::manifest::Manifest;
+use aor::report::{
+    Format, MatrixReporter, ReadmeReporter, Reporter, RunRecord, Status, Summary, Tee, TextReporter,
+};
+use aor::serve::{self, Input, Request};
+use aor::timings::{BuildInfo, FileDb, HistoryEntry, MemoryDb, TimingsDb};
+use aor::validate::{Issue, Severity, Validator};
+use aor::watch::{self, Answers, Watcher};
+use aor::{Solution, Tag, solutions};
 use colored::Colorize;
 use itertools::Itertools;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
+use std::io::{BufRead, Write};
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+/// Options of all the commands.
+const OPTIONS: [Opt; 22] = [
+    Opt::flag("--alt", "Include the alternative solutions").short('a'),
+    Opt::flag(
+        "--single",
+        "Run one solution with an input file: -r YEAR:DAY[:ALT] INPUT",
+    )
+    .short('r'),
+    Opt::flag("--elapsed", "Show the elapsed time, with -r"),
+    Opt::flag("--verbose", "Use verbose output, with -r").short('v'),
+    Opt::flag("--list", "Same as the list command").short('l'),
+    Opt::with_value(
+        "--format",
+        "FORMAT",
+        "Output format: text, json, jsonl, csv, junit or markdown",
+    ),
+    Opt::with_value("--jobs", "N", "Number of parallel runs, 0 for one per CPU"),
+    Opt::with_value(
+        "--serial",
+        "FILTERS",
+        "Solutions to run alone, like 2016:5,2018:11",
+    ),
+    Opt::with_value("--timeout", "SECS", "Wall-clock limit of a solution"),
+    Opt::with_value(
+        "--db",
+        "DB",
+        "Timings database: sqlite, json[:PATH], memory or none",
+    ),
+    Opt::with_value(
+        "--compare",
+        "REV",
+        "Compare the timings with the ones of a commit",
+    ),
+    Opt::with_value(
+        "--threshold",
+        "PCT",
+        "Regression threshold of --compare (default 10)",
+    ),
+    Opt::flag(
+        "--from-db",
+        "Timings tables from the database, without running",
+    ),
+    Opt::with_value(
+        "--tag",
+        "TAGS",
+        "Only the solutions with a tag: grid, graph, vm, z3, rayon",
+    ),
+    Opt::flag("--skip-slow", "Skip the slow solutions"),
+    Opt::flag("--users", "Run the inputs of all the users of data/"),
+    Opt::with_value(
+        "--user",
+        "ID",
+        "Run the inputs of a user of data/, or me or mine",
+    ),
+    Opt::with_value("--part", "N", "Solve only part 1 or 2"),
+    Opt::flag(
+        "--samples",
+        "Run the samples of the puzzles instead of the inputs",
+    ),
+    Opt::with_value(
+        "--watch",
+        "YEAR:DAY[:ALT]",
+        "Run a solution again when its sources, input or samples change",
+    ),
+    Opt::with_value(
+        "--manifest",
+        "FILE",
+        "Write the build, the machine, the inputs and the results of the runs",
+    ),
+    Opt::flag(
+        "--serve-stdio",
+        "Run the solutions of the JSON requests of the standard input, one per line",
+    ),
+];
+
+/// Options of the bench command, also allowed by the run command with `--bench`.
+const BENCH_OPTIONS: [Opt; 2] = [
+    Opt::with_value("--warmup", "N", "Number of warmup runs (default 3)"),
+    Opt::with_value("--runs", "N", "Number of timed runs (default 10)"),
+];
+
+const RECORD: Opt = Opt::flag("--record", "Write the missing expected answers");
+
+/// Options of the run command, the default one.
+const RUN_OPTIONS: [Opt; 6] = [
+    Opt::flag("--bench", "Same as the bench command"),
+    BENCH_OPTIONS[0],
+    BENCH_OPTIONS[1],
+    Opt::flag("--readme", "Update the timings of the READMEs"),
+    RECORD,
+    Opt::flag("--verify", "Same as the check command"),
+];
+
+/// Options of the compare command.
+const COMPARE_OPTIONS: [Opt; 2] = [
+    Opt::with_value("--lang", "LANGS", "Only some languages, like python,c"),
+    Opt::with_value(
+        "--config",
+        "FILE",
+        "TOML file with the build and run commands of the languages",
+    ),
+];
+
+const COMMANDS: [Command; 8] = [
+    Command {
+        name: "run",
+        help: "Run the solutions (default)",
+        options: &RUN_OPTIONS,
+    },
+    Command {
+        name: "list",
+        help: "List the solutions",
+        options: &[],
+    },
+    Command {
+        name: "bench",
+        help: "Benchmark the solutions",
+        options: &BENCH_OPTIONS,
+    },
+    Command {
+        name: "check",
+        help: "Verify the answers, exit with an error on any mismatch",
+        options: &[RECORD],
+    },
+    Command {
+        name: "compare",
+        help: "Compare the answers of the other languages with the Rust ones",
+        options: &COMPARE_OPTIONS,
+    },
+    Command {
+        name: "new",
+        help: "Create a new day from the template: new YEAR DAY",
+        options: &[Opt::with_value(
+            "--title",
+            "TITLE",
+            "Title of the puzzle (default TBD)",
+        )],
+    },
+    Command {
+        name: "inputs",
+        help: "Manage the input store: inputs import DIR|TARBALL, list [YEAR[:DAY]] or verify",
+        options: &[Opt::with_value(
+            "--store",
+            "DIR",
+            "Directory of the input store (default data/.store)",
+        )],
+    },
+    Command {
+        name: "replay",
+        help: "Run again the runs of a manifest and show the differences: replay MANIFEST",
+        options: &[],
+    },
+];
+
+const SPEC: Spec = Spec {
+    about: "Advent of Code solutions runner",
+    params: "[YEAR[:DAY]] [INPUT.toml]",
+    options: &OPTIONS,
+    commands: &COMMANDS,
+    default_command: Some("run"),
+    lenient: false,
+};
 
 fn main() {
-    let args = aoc::parse_args_raw();
+    // in a YEAR/dayDAY directory, we act as the standalone daily binary
+    match run_day_directory() {
+        Err(err) => {
+            println!("fatal: {err}");
+            return;
+        }
+        Ok(true) => return,
+        Ok(false) => {}
+    }
+
+    let args = Args::parse_spec(&SPEC);
 
     // runall.py mode
     if args.has_option("-r") {
@@ -15,46 +203,65 @@ fn main() {
         return;
     }
 
+    // a harness drives the solutions through stdin and stdout
+    if args.has_option("--serve-stdio") {
+        serve_stdio(&args);
+        return;
+    }
+
+    // rebuild and run a solution on change
+    if let Some(filter) = args.option_value("--watch") {
+        watch(&args, filter);
+        return;
+    }
+
     // print list of solutions, can be filtered
-    if args.has_option("-l") {
-        list_solutions();
+    if args.has_option("--list") || args.command() == Some("list") {
+        list_solutions(&args);
         return;
     }
 
-    // in a YEAR/dayDAY directory, we act as the standalone daily binary
-    match run_day_directory() {
-        Err(err) => {
-            println!("fatal: {err}");
-            return;
-        }
-        Ok(true) => return,
-        Ok(false) => {}
+    // the runs of a manifest
+    if args.command() == Some("replay") {
+        replay(&args);
+        return;
+    }
+
+    // the input store
+    if args.command() == Some("inputs") {
+        inputs(&args);
+        return;
+    }
+
+    // scaffolding of a new day
+    if args.command() == Some("new") {
+        new_day(&args);
+        return;
+    }
+
+    // agreement of the implementations in other languages
+    if args.command() == Some("compare") {
+        compare(&args);
+        return;
+    }
+
+    // README timings from the database, without running the solutions
+    if args.has_option("--from-db") {
+        readme_from_db(&args);
+        return;
     }
 
     // run all or filtered solutions
     run_all(&args);
 }
 
-fn run_day_single(args: &aoc::Args) {
+fn run_day_single(args: &Args) {
     if args.params().len() != 2 {
         println!("-r requires a filter and a path");
         std::process::exit(1);
     }
 
-    let mut year: Option<u16> = None;
-    let mut day: Option<u8> = None;
-    let mut alt: Option<String> = None;
-
-    let filter = &args.params()[0];
-    let re = regex::Regex::new(r"(\d+):(\d+)(?:[:_](\w+))?").unwrap();
-    if let Some(caps) = re.captures(filter) {
-        year = caps[1].parse().ok();
-        day = caps[2].parse().ok();
-        if let Some(s) = caps.get(3) {
-            alt = Some(s.as_str().to_string());
-        }
-    }
-
+    let (year, day, alt) = parse_solution(&args.params()[0]);
     let sols = solutions(year, day, &alt);
 
     if sols.len() != 1 {
@@ -74,9 +281,210 @@ fn run_day_single(args: &aoc::Args) {
     // let _ = aor::rundb::update_db(sol.year, sol.day, &data, elapsed);
 }
 
-fn list_solutions() {
-    let args = aoc::parse_args_raw();
+/// Answer the JSON requests of the standard input, one per line, until its end.
+fn serve_stdio(args: &Args) {
+    let options = RunOptions::from_args(args);
+    let mut stdout = std::io::stdout().lock();
+
+    for line in std::io::stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+
+        let response = match Request::parse(&line) {
+            Ok(request) => serve_request(&request, &options),
+            Err((id, err)) => serve::error(&id, &err),
+        };
+
+        // the harness has gone
+        if writeln!(stdout, "{response}")
+            .and_then(|()| stdout.flush())
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Run the solution of a request, and return the response line.
+///
+/// The requests are independent: the input is not checked against the ones of the previous requests.
+fn serve_request(request: &Request, options: &RunOptions) -> String {
+    let Some(sol) = solutions(Some(request.year), Some(request.day), &request.alt)
+        .into_iter()
+        .next()
+    else {
+        return serve::error(&request.id, "unknown solution");
+    };
+
+    let job = match &request.input {
+        Input::Path(path) => match std::fs::read_to_string(path) {
+            Ok(data) => {
+                let (answer1, answer2) =
+                    answers::read(&answers::answer_path(Path::new(path))).unwrap_or_default();
+                Job {
+                    sol,
+                    user: None,
+                    data,
+                    answer1,
+                    answer2,
+                    source: path.clone(),
+                    part: None,
+                    issues: Vec::new(),
+                }
+            }
+            Err(err) => return serve::error(&request.id, &format!("{path}: {err}")),
+        },
+        Input::Data(data) => Job {
+            sol,
+            user: None,
+            data: data.clone(),
+            answer1: String::new(),
+            answer2: String::new(),
+            source: "(data)".to_string(),
+            part: None,
+            issues: Vec::new(),
+        },
+        Input::Default => match load_job(&sol, false) {
+            Ok(job) => job,
+            Err(path) => return serve::error(&request.id, &format!("missing file {path}")),
+        },
+    };
+
+    let job = Job {
+        answer1: request.answer1.clone().unwrap_or(job.answer1),
+        answer2: request.answer2.clone().unwrap_or(job.answer2),
+        part: request.part,
+        ..job
+    }
+    .validate(&mut Validator::default());
+
+    serve::response(&request.id, &run_solution(&job, options))
+}
+
+/// Parse a `YEAR:DAY[:ALT]` filter of a single solution.
+fn parse_solution(filter: &str) -> (Option<u16>, Option<u8>, Option<String>) {
+    let re = regex::Regex::new(r"(\d+):(\d+)(?:[:_](\w+))?").unwrap();
+
+    re.captures(filter).map_or((None, None, None), |caps| {
+        (
+            caps[1].parse().ok(),
+            caps[2].parse().ok(),
+            caps.get(3).map(|s| s.as_str().to_string()),
+        )
+    })
+}
+
+/// Run a solution and its samples, then again each time its input changes. A change of its sources
+/// or of its samples rebuilds the runner and restarts it, with the answers to compare with.
+fn watch(args: &Args, filter: &str) {
+    let (year, day, alt) = parse_solution(filter);
+    let sols = solutions(year, day, &alt);
+
+    if sols.len() != 1 {
+        println!(
+            "--watch requires exactly one solution ({} found with year={year:?} day={day:?} alt={alt:?})",
+            sols.len()
+        );
+        std::process::exit(1);
+    }
+
+    let sol = &sols[0];
+    let options = RunOptions::from_args(args);
+
+    // the path of the binary, before cargo replaces it
+    let exe = std::env::current_exe().expect("cannot find the path of the runner");
+
+    let module = sol.alt.as_ref().map_or_else(
+        || format!("day{}", sol.day),
+        |alt| format!("day{}_{alt}", sol.day),
+    );
+    let sources = root_dir()
+        .join("src")
+        .join(format!("year{}", sol.year))
+        .join(module);
+    let (input, answer) = find_input_path(sol, false);
+
+    let mut watcher = Watcher::new(
+        vec![sources, input.clone(), answer.clone()],
+        Duration::from_millis(500),
+    );
+
+    let mut previous = Answers::previous();
+    let mut reporter = TextReporter;
+
+    loop {
+        let mut validator = Validator::default();
+
+        match load_job(sol, false) {
+            Ok(job) => {
+                let job = job.validate(&mut validator);
+                reporter.start(sol, &job.source);
+                let record = run_solution(&job, &options);
+                reporter.record(&record);
+
+                let answers = Answers {
+                    part1: record.part1,
+                    part2: record.part2,
+                    elapsed: record.elapsed,
+                };
+                if let Some(previous) = &previous {
+                    for line in answers.diff(previous) {
+                        println!("  {} : {}", "Changes".cyan(), line.cyan());
+                    }
+                }
+                previous = Some(answers);
+            }
+            Err(path) => reporter.missing(sol, &path),
+        }
+
+        if !sol.samples.is_empty() {
+            let ok = Loader::Samples
+                .jobs(sol)
+                .into_iter()
+                .flatten()
+                .map(|job| job.validate(&mut validator))
+                .filter(|job| run_solution(job, &options).status == Status::Success)
+                .count();
+            let line = format!("{ok}/{} ok", sol.samples.len());
+            if ok == sol.samples.len() {
+                println!("  {} : {}", "Samples".cyan(), line.green());
+            } else {
+                println!("  {} : {}", "Samples".cyan(), line.red());
+            }
+        }
+
+        println!();
+        println!("{}", "Watching for changes, Ctrl-C to quit".dimmed());
+
+        // the input is read again, the sources and the samples need a new build
+        loop {
+            let changed = watcher.wait();
+            for path in &changed {
+                println!(
+                    "{} {}",
+                    path.display().to_string().italic(),
+                    "changed".dimmed()
+                );
+            }
+
+            if changed.iter().all(|path| *path == input || *path == answer) {
+                break;
+            }
+            if watch::rebuild(&root_dir()) {
+                watch::restart(&exe, previous.as_ref());
+            }
+            println!("{}", "Build failed, waiting for a fix".red());
+        }
+
+        println!();
+    }
+}
 
+fn list_solutions(args: &Args) {
     // get the year or year/day filter
     let mut year: Option<u16> = None;
     let mut day: Option<u8> = None;
@@ -94,19 +502,357 @@ fn list_solutions() {
         }
     }
 
-    for sol in &solutions(year, day, &alt) {
+    for sol in &select(args, solutions(year, day, &alt)) {
         let alt = sol
             .alt
             .clone()
             .map_or(String::new(), |alt| format!(" ({})", alt.magenta()));
 
+        let tags = sol
+            .tags
+            .iter()
+            .map(|tag| tag.as_str())
+            .chain(sol.slow.then_some("slow"))
+            .join(",");
+        let tags = if tags.is_empty() {
+            String::new()
+        } else {
+            format!(" [{tags}]")
+        };
+
         println!(
-            "{} {} {} {:2}{}",
+            "{} {} {} {:2}{} {}{}",
             "Year".green(),
             sol.year,
             "day".green(),
             sol.day,
-            alt
+            alt,
+            sol.title,
+            tags.dimmed()
+        );
+    }
+}
+
+/// Keep the solutions with one of the `--tag` tags, and drop the slow ones with `--skip-slow`.
+fn select(args: &Args, sols: Vec<Solution>) -> Vec<Solution> {
+    let tags: Vec<Tag> = args
+        .option_value("--tag")
+        .map(|tags| {
+            tags.split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    std::process::exit(2);
+                })
+        })
+        .unwrap_or_default();
+
+    let skip_slow = args.has_option("--skip-slow");
+
+    sols.into_iter()
+        .filter(|sol| tags.is_empty() || sol.tags.iter().any(|tag| tags.contains(tag)))
+        .filter(|sol| !(skip_slow && sol.slow))
+        .collect()
+}
+
+/// The root directory of the repository.
+fn root_dir() -> PathBuf {
+    std::env::var("CARGO_MANIFEST_DIR").map_or_else(|_| PathBuf::from("."), PathBuf::from)
+}
+
+/// Run again the runs of a manifest written with `--manifest`, and compare the answers and the timings.
+fn replay(args: &Args) {
+    let Some(path) = args.params().first() else {
+        eprintln!("replay requires a manifest");
+        std::process::exit(2);
+    };
+
+    let manifest = Manifest::read(Path::new(path)).unwrap_or_else(|err| {
+        eprintln!("invalid manifest {path}: {err}");
+        std::process::exit(2);
+    });
+
+    for change in manifest.build_changes(&BuildInfo::current()) {
+        println!("{}", change.yellow());
+    }
+
+    let options = RunOptions::from_args(args);
+    let store = InputStore::open(&root_dir().join("data").join(".store")).ok();
+    let mut validator = Validator::default();
+    let mut changed = 0;
+
+    for run in &manifest.runs {
+        let sol = solutions(Some(run.year), Some(run.day), &run.alt)
+            .into_iter()
+            .next();
+        let Some((sol, data)) =
+            sol.and_then(|sol| Some((sol.clone(), run.find_input(&sol, store.as_ref())?)))
+        else {
+            println!(
+                "{:<28} {}",
+                run.name(),
+                format!(
+                    "solution or input {} not found",
+                    run.digest.get(..12).unwrap_or(&run.digest)
+                )
+                .red()
+            );
+            changed += 1;
+            continue;
+        };
+
+        let job = Job {
+            sol,
+            user: run.user.clone(),
+            data,
+            answer1: run.part1.clone(),
+            answer2: run.part2.clone(),
+            source: run.source.clone(),
+            part: run.part,
+            issues: Vec::new(),
+        }
+        .validate(&mut validator);
+
+        let (same, line) = run.compare(&run_solution(&job, &options));
+        if same {
+            println!("{:<28} {}", run.name(), line);
+        } else {
+            println!("{:<28} {}", run.name(), line.red());
+            changed += 1;
+        }
+    }
+
+    println!();
+    println!(
+        "{} run(s) replayed, {} with different answers",
+        manifest.runs.len(),
+        changed
+    );
+
+    if changed != 0 {
+        std::process::exit(1);
+    }
+}
+
+/// The input store of `--store`, or the default one in `data/.store`.
+fn open_store(args: &Args) -> InputStore {
+    let dir = args
+        .option_value("--store")
+        .map_or_else(|| root_dir().join("data").join(".store"), PathBuf::from);
+
+    InputStore::open(&dir).unwrap_or_else(|err| {
+        eprintln!("invalid input store {}: {err}", dir.display());
+        std::process::exit(2);
+    })
+}
+
+/// Import inputs into the input store, list them or verify them.
+fn inputs(args: &Args) {
+    let mut store = open_store(args);
+    let params = args.params();
+
+    match params.first().map(String::as_str) {
+        Some("import") if params.len() > 1 => {
+            for path in &params[1..] {
+                match store.import(Path::new(path), args.option_value("--user")) {
+                    Ok(imported) => println!(
+                        "{path}: {} added, {} updated, {} unchanged",
+                        imported.added, imported.updated, imported.unchanged
+                    ),
+                    Err(err) => {
+                        eprintln!("{path}: {}", err.to_string().red());
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        Some("list") => {
+            let (year, day) = params
+                .get(1)
+                .map_or((None, None), |filter| parse_filter(filter));
+
+            for input in store.inputs().into_iter().filter(|input| {
+                year.is_none_or(|year| year == input.year) && day.is_none_or(|day| day == input.day)
+            }) {
+                println!(
+                    "{} day {:2} {:<12} {} {:>16} {:<16} {}",
+                    input.year,
+                    input.day,
+                    input.user,
+                    input.short_digest().dimmed(),
+                    input.answer1,
+                    input.answer2,
+                    input.source.italic().dimmed()
+                );
+            }
+        }
+        Some("verify") => {
+            let problems = store.verify();
+            for (input, problem) in &problems {
+                println!(
+                    "{} day {} {} {}: {}",
+                    input.year,
+                    input.day,
+                    input.user,
+                    input.short_digest(),
+                    problem.to_string().red()
+                );
+            }
+            println!(
+                "{} input(s), {} problem(s)",
+                store.inputs().len(),
+                problems.len()
+            );
+            if !problems.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!("inputs requires import DIR|TARBALL..., list [YEAR[:DAY]] or verify");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Create the solution of a new day from the template, with its sample and its README row.
+fn new_day(args: &Args) {
+    let (Some(year), Some(day)) = parse_filter(&args.params().join(" ")) else {
+        eprintln!("new requires a year and a day");
+        std::process::exit(2);
+    };
+
+    let title = args.option_value("--title").unwrap_or("TBD");
+
+    match aor::scaffold::create(&root_dir(), year, day, title) {
+        Ok(created) => {
+            for path in created {
+                println!("{} {}", "created".green(), path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err.red());
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Run the implementations in other languages of the solutions, print their agreement matrix
+/// with the Rust ones, and exit with an error if any disagrees.
+fn compare(args: &Args) {
+    let mut languages = compare::languages();
+
+    if let Some(path) = args.option_value("--config") {
+        let config = std::fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| compare::configure(&mut languages, &content));
+        if let Err(err) = config {
+            eprintln!("invalid --config: {err}");
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(names) = args.option_value("--lang") {
+        let names: Vec<_> = names.split(',').collect();
+        languages.retain(|lang| {
+            names
+                .iter()
+                .any(|name| lang.name.eq_ignore_ascii_case(name))
+        });
+    }
+
+    let (year, day) = args
+        .params()
+        .first()
+        .map_or((None, None), |filter| parse_filter(filter));
+
+    let timeout = RunOptions::from_args(args).timeout;
+    let mut matrix = compare::Matrix::default();
+
+    for sol in select(args, solutions(year, day, &None)) {
+        let year_dir = root_dir().join("src").join(format!("year{}", sol.year));
+        let implementations = compare::discover(
+            &year_dir.join(format!("day{}", sol.day)),
+            sol.day,
+            &languages,
+        );
+        if implementations.is_empty() {
+            continue;
+        }
+
+        let (input, _) = find_input_path(&sol, false);
+        let Ok(data) = std::fs::read_to_string(&input) else {
+            eprintln!(
+                "{} day {}: missing file {}",
+                sol.year,
+                sol.day,
+                input.display()
+            );
+            continue;
+        };
+
+        let (outcome, elapsed) = aor::guard::run(sol.solve, &aoc::normalize_input(data), timeout);
+        let Outcome::Answers(part1, part2) = outcome else {
+            eprintln!("{} day {}: the Rust solution failed", sol.year, sol.day);
+            continue;
+        };
+
+        let build_dir = root_dir()
+            .join("target")
+            .join("build")
+            .join(format!("year{}", sol.year));
+
+        for (lang, source) in implementations {
+            let result = compare::run(lang, &source, &build_dir, &input, timeout);
+            if let Err(failure) = &result
+                && !matches!(failure, compare::Failure::Unavailable(_))
+            {
+                eprintln!("{} day {} {}: {failure:?}", sol.year, sol.day, lang.name);
+            }
+            let verdict = compare::Verdict::new(result, &part1, &part2);
+            matrix.add(sol.year, sol.day, elapsed, &lang.name, verdict);
+        }
+    }
+
+    print!("{}", matrix.render());
+
+    if matrix.disagreements() != 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Build the README timings tables from the best times recorded in the database
+/// for the current inputs, and print them or update the READMEs with `--readme`.
+fn readme_from_db(args: &Args) {
+    let Some(db) = Timings::open_db(args) else {
+        eprintln!("--from-db requires a timings database (--db=...)");
+        std::process::exit(2);
+    };
+
+    let (year, day) = args
+        .params()
+        .first()
+        .map_or((None, None), |filter| parse_filter(filter));
+
+    let mut timings = BTreeMap::new();
+
+    for sol in select(args, solutions(year, day, &None)) {
+        if let Ok(job) = load_job(&sol, false)
+            && let Ok(Some(best)) = db.best(sol.year, sol.day, &job.data)
+        {
+            timings.insert((sol.year, sol.day), best);
+        }
+    }
+
+    if args.has_option("--readme") {
+        if let Err(err) = aor::markdown::update_readmes(&root_dir(), &timings) {
+            eprintln!("failed to update the READMEs: {err}");
+            std::process::exit(1);
+        }
+    } else {
+        print!(
+            "{}",
+            aor::markdown::timings_tables(&timings, aor::markdown::YEARS_PER_TABLE)
         );
     }
 }
@@ -144,12 +890,14 @@ fn run_day_directory() -> Result<bool, Box<dyn Error>> {
 
                 if let Some(sol) = solutions(year, day, &alt).first() {
                     if std::env::args().len() == 1 {
-                        run_solution_from_file(
-                            sol,
-                            true,
-                            #[cfg(feature = "timingsdb")]
-                            None,
-                        );
+                        let mut reporter = TextReporter;
+                        match load_job(sol, true) {
+                            Ok(job) => {
+                                reporter.start(sol, &job.source);
+                                reporter.record(&run_solution(&job, &RunOptions::default()));
+                            }
+                            Err(path) => reporter.missing(sol, &path),
+                        }
                     } else {
                         (sol.main)();
                     }
@@ -163,86 +911,48 @@ fn run_day_directory() -> Result<bool, Box<dyn Error>> {
     Ok(false)
 }
 
-fn print_part_result(part: u8, answer: &str, ok: &str, year: u16, day: u8) {
-    if part == 2 && (day == 25 || (year >= 2025 && day == 12)) {
-        println!(
-            "  {}  : {}",
-            "Part 2".yellow(),
-            "n/a".dimmed() // "Merry Christmas".bright_blue()
-        );
-    } else {
-        print!("  {}  : ", format!("Part {part}").yellow());
-        if ok.is_empty() {
-            println!("{answer}");
-        } else if answer.trim_ascii() == ok.trim_ascii() {
-            println!("{}", answer.bright_green());
-        } else {
-            println!("{}", answer.bright_red());
-        }
-    }
-}
+/// Parse a `YEAR[:DAY]` filter.
+fn parse_filter(filter: &str) -> (Option<u16>, Option<u8>) {
+    let re = regex::Regex::new(r"(\d+)").unwrap();
 
-fn run_all(args: &aoc::Args) {
-    #[cfg(feature = "timingsdb")]
-    let db = aor::rundb::RunDb::new().expect("failed to open database");
+    let mut m = re.find_iter(filter);
 
-    // load data from TOML if any
-    let toml_data = args.params().iter().find_map(|path| {
-        if std::path::Path::new(path)
-            .extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
-        {
-            let content = std::fs::read_to_string(path).expect("failed to read TOML file");
+    let year = m.next().and_then(|y| y.as_str().parse().ok());
+    let day = m.next().and_then(|d| d.as_str().parse().ok());
 
-            Some((load_toml(&content), path))
-        } else {
-            None
-        }
-    });
+    (year, day)
+}
 
-    // set the runner, either from TOML or from default input files
-    let runner = |sol: &Solution| -> Option<(Duration, Duration, bool, bool)> {
-        if let Some((data, file)) = &toml_data {
-            if let Some((input, part1, part2)) = data.get(&(sol.year, sol.day)) {
-                let source = format!("({file} [{}.{}])", sol.year, sol.day);
-                Some(run_solution(
-                    sol,
-                    input,
-                    part1,
-                    part2,
-                    &source,
-                    #[cfg(feature = "timingsdb")]
-                    Some(&db as &dyn aor::rundb::TimingsDb),
-                ))
-            } else {
-                None
-            }
-        } else {
-            run_solution_from_file(
-                sol,
-                false,
-                #[cfg(feature = "timingsdb")]
-                Some(&db as &dyn aor::rundb::TimingsDb),
-            )
+/// Get the value of a `--name=value` option, or exit if it is invalid.
+fn option_value<T>(args: &Args, name: &str, default: T) -> T
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match args.value_of(name) {
+        Ok(value) => value.unwrap_or(default),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(2);
         }
-    };
+    }
+}
 
-    // get the year or year/day filter
-    let mut year: Option<u16> = None;
-    let mut day: Option<u8> = None;
+fn run_all(args: &Args) {
+    let format = option_value(args, "--format", Format::Text);
+    let mut reporter = make_reporter(args, format);
+    let options = RunOptions::from_args(args);
 
-    if !args.params().is_empty() {
-        let re = regex::Regex::new(r"(\d+)").unwrap();
+    let mut timings = Timings::new(args);
 
-        let mut m = re.find_iter(&args.params()[0]);
+    let loader = Loader::from_args(args);
+    let mut validator = Validator::default();
 
-        if let Some(y) = m.next() {
-            year = y.as_str().parse().ok();
-        }
-        if let Some(d) = m.next() {
-            day = d.as_str().parse().ok();
-        }
-    }
+    // get the year or year/day filter
+    let (year, day) = args
+        .params()
+        .first()
+        .map_or((None, None), |filter| parse_filter(filter));
 
     // get the alternative filter
     let alt = if args.has_option("-a") {
@@ -252,46 +962,333 @@ fn run_all(args: &aoc::Args) {
     };
 
     // and apply it to the solution inventory
-    let sols = solutions(year, day, &alt);
+    let sols = select(args, solutions(year, day, &alt));
 
     // run solutions
-    println!("💫 {} 🎄✨ 💫", "Advent of Code".green());
-    println!();
+    reporter.begin();
+
+    let mut summary = Summary {
+        timed: timings.db.is_some(),
+        ..Summary::default()
+    };
 
-    let mut total_elapsed = Duration::ZERO;
-    let mut total_best = Duration::ZERO;
-    let mut puzzles = 0;
-    let mut success = 0;
-    let mut failed = 0;
+    let mut manifest = args
+        .option_value("--manifest")
+        .map(|path| (path, Manifest::new(timings.build.clone())));
+
+    // timings of parallel, aborted, single part or sample runs are not recorded, they would be meaningless,
+    // like the ones measured while a timed out solution keeps a core busy
+    let samples = matches!(loader, Loader::Samples);
+    let mut unrecorded = 0;
+    let mut with_best = |job: &Job, record: RunRecord, timed: bool| -> RunRecord {
+        if timed && !samples && record.error.is_none() && record.part.is_none() {
+            if aor::guard::abandoned() == 0 {
+                timings.record(job, record)
+            } else {
+                unrecorded += 1;
+                record
+            }
+        } else {
+            record
+        }
+    };
 
-    for sol in sols {
-        if let Some((elapsed, best, ok, ko)) = runner(&sol) {
-            puzzles += 1;
-            total_elapsed += elapsed;
-            total_best += best;
-            success += i32::from(ok);
-            failed += i32::from(ko);
+    if options.jobs == 1 {
+        for sol in sols {
+            for job in loader.jobs(&sol) {
+                match job {
+                    Ok(job) => {
+                        let job = job.validate(&mut validator);
+                        reporter.start(&sol, &job.source);
+                        let record = with_best(&job, run_solution(&job, &options), true);
+                        if let Some((_, manifest)) = &mut manifest {
+                            manifest.add(&job.data, &record);
+                        }
+                        summary.add(&record);
+                        reporter.record(&record);
+                    }
+                    Err(path) => reporter.missing(&sol, &path),
+                }
+            }
+        }
+    } else {
+        let prepared: Vec<_> = sols
+            .iter()
+            .flat_map(|sol| loader.jobs(sol).into_iter().map(move |job| (sol, job)))
+            .map(|(sol, job)| (sol, job.map(|job| job.validate(&mut validator))))
+            .collect();
+
+        let records = run_parallel(&prepared, &options);
+
+        // report in the year/day/alt order
+        for ((sol, job), record) in prepared.iter().zip(records) {
+            match (job, record) {
+                (Ok(job), Some(record)) => {
+                    reporter.start(sol, &job.source);
+                    let record = with_best(job, record, options.is_serial(sol));
+                    if let Some((_, manifest)) = &mut manifest {
+                        manifest.add(&job.data, &record);
+                    }
+                    summary.add(&record);
+                    reporter.record(&record);
+                }
+                (Err(path), _) => reporter.missing(sol, path),
+                _ => (),
+            }
         }
     }
 
-    if puzzles > 1 {
-        println!();
+    reporter.end(&summary);
 
-        #[cfg(feature = "timingsdb")]
-        println!(
-            "Elapsed: {:.6}s for {puzzles} puzzle(s) - {} - success: {success}, failed: {failed}",
-            total_elapsed.as_secs_f64(),
-            format!("best: {:.6}s", total_best.as_secs_f64()).bold(),
+    if unrecorded != 0 {
+        eprintln!(
+            "{}",
+            format!(
+                "warning: {unrecorded} timing(s) not recorded, measured while a timed out solution was still running"
+            )
+            .yellow()
         );
+    }
 
-        #[cfg(not(feature = "timingsdb"))]
-        println!(
-            "Elapsed: {:.6}s for {puzzles} puzzle(s) - success: {success}, failed: {failed}",
-            total_elapsed.as_secs_f64(),
-        );
+    if let Some((path, manifest)) = &manifest
+        && let Err(err) = manifest.write(Path::new(path))
+    {
+        eprintln!("failed to write the manifest {path}: {err}");
+    }
+
+    timings.print_comparison(format == Format::Text);
+
+    if (args.has_option("--verify") || args.command() == Some("check") || samples)
+        && summary.failed + summary.timeout + summary.panic + summary.invalid != 0
+    {
+        std::process::exit(1);
+    }
+}
+
+/// Create the reporter of the output format, followed by the READMEs update (`--readme`),
+/// the answers recording (`--record`) and the users pass matrix (`--users`) if asked.
+/// The samples runs neither update the READMEs nor record answers.
+fn make_reporter(args: &Args, format: Format) -> Box<dyn Reporter> {
+    let mut reporters = vec![format.reporter()];
+    let samples = args.has_option("--samples");
+
+    if args.has_option("--readme") && !samples {
+        reporters.push(Box::new(ReadmeReporter::new(root_dir())));
+    }
+    if args.has_option("--record") && !samples {
+        reporters.push(Box::new(Recorder::default()));
+    }
+    if args.has_option("--users") || args.option_value("--user").is_some() {
+        reporters.push(Box::new(MatrixReporter::new(format == Format::Text)));
+    }
+
+    if reporters.len() == 1 {
+        reporters.pop().unwrap()
+    } else {
+        Box::new(Tee(reporters))
     }
 }
 
+/// The timings database, with the context of the runs and the optional baseline to compare with.
+struct Timings {
+    db: Option<Box<dyn TimingsDb>>,
+    build: BuildInfo,
+    /// Commit to compare with, and the regression threshold in percent.
+    baseline: Option<(String, f64)>,
+    /// Name, elapsed time and baseline time of the compared runs.
+    compared: Vec<(String, Duration, Duration)>,
+}
+
+impl Timings {
+    /// Open the timings database selected by `--db=sqlite|json[:PATH]|memory|none`.
+    fn open_db(args: &Args) -> Option<Box<dyn TimingsDb>> {
+        let default = if cfg!(feature = "timingsdb") {
+            "sqlite"
+        } else {
+            "none"
+        };
+
+        let db = args.option_value("--db").unwrap_or(default);
+        let (backend, path) = db.split_once(':').unwrap_or((db, ""));
+
+        let db: Result<Box<dyn TimingsDb>, Box<dyn Error>> = match backend {
+            "none" => return None,
+            "memory" => Ok(Box::new(MemoryDb::new())),
+            "json" => {
+                let path = if path.is_empty() {
+                    root_dir().join(".timings.json")
+                } else {
+                    PathBuf::from(path)
+                };
+                FileDb::open(&path).map(|db| Box::new(db) as Box<dyn TimingsDb>)
+            }
+            #[cfg(feature = "timingsdb")]
+            "sqlite" => aor::rundb::RunDb::new().map(|db| Box::new(db) as Box<dyn TimingsDb>),
+            _ => Err(format!("unknown backend {backend}").into()),
+        };
+
+        match db {
+            Ok(db) => Some(db),
+            Err(err) => {
+                eprintln!("invalid --db: {err}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    fn new(args: &Args) -> Self {
+        let baseline = args.option_value("--compare").map(|rev| {
+            let Some(commit) = aor::timings::resolve_commit(rev) else {
+                eprintln!("invalid --compare: unknown revision {rev}");
+                std::process::exit(2);
+            };
+            (commit, option_value(args, "--threshold", 10.))
+        });
+
+        let db = Self::open_db(args);
+        if baseline.is_some() && !db.as_ref().is_some_and(|db| db.has_history()) {
+            eprintln!(
+                "invalid --compare: needs a timings database with a history (--db=sqlite or json)"
+            );
+            std::process::exit(2);
+        }
+
+        Self {
+            db,
+            build: BuildInfo::current(),
+            baseline,
+            compared: Vec::new(),
+        }
+    }
+
+    /// Record the run in the database and the history, and set its best time.
+    fn record(&mut self, job: &Job, record: RunRecord) -> RunRecord {
+        let Some(db) = &self.db else {
+            return record;
+        };
+
+        let sol = &job.sol;
+        let elapsed = record.bench.map_or(record.elapsed, |stats| stats.min);
+
+        let best = db
+            .update(sol.year, sol.day, &job.data, elapsed)
+            .unwrap_or(elapsed);
+
+        let entry = HistoryEntry {
+            year: sol.year,
+            day: sol.day,
+            alt: sol.alt.as_deref(),
+            data: &job.data,
+            elapsed,
+            build: &self.build,
+        };
+        if let Err(err) = db.add_history(&entry) {
+            eprintln!("failed to update history: {err}");
+        }
+
+        if let Some((commit, _)) = &self.baseline
+            && let Ok(Some(base)) =
+                db.history_best(sol.year, sol.day, sol.alt.as_deref(), &job.data, commit)
+        {
+            self.compared.push((record.name(), elapsed, base));
+        }
+
+        RunRecord { best, ..record }
+    }
+
+    /// Print the runs that are slower than the baseline beyond the threshold.
+    fn print_comparison(&self, stdout: bool) {
+        let Some((commit, threshold)) = &self.baseline else {
+            return;
+        };
+
+        let print = |line: String| {
+            if stdout {
+                println!("{line}");
+            } else {
+                eprintln!("{line}");
+            }
+        };
+
+        // a zero baseline has no meaningful ratio
+        let (compared, zero): (Vec<_>, Vec<_>) = self
+            .compared
+            .iter()
+            .partition(|(_, _, base)| !base.is_zero());
+
+        let regressions: Vec<_> = compared
+            .iter()
+            .filter(|(_, elapsed, base)| {
+                elapsed.as_secs_f64() > base.as_secs_f64() * (1. + threshold / 100.)
+            })
+            .collect();
+
+        print(String::new());
+        print(format!(
+            "Compared with {}: {} puzzle(s), {} regression(s) beyond {threshold}%",
+            &commit[..commit.len().min(10)],
+            compared.len(),
+            regressions.len()
+        ));
+        if self.compared.is_empty() {
+            print("  no timings recorded at this commit".to_string());
+        }
+        if !zero.is_empty() {
+            print(format!(
+                "  {} puzzle(s) skipped, with a zero baseline",
+                zero.len()
+            ));
+        }
+
+        for (name, elapsed, base) in regressions {
+            print(format!(
+                "  {name:<24} {:>12.3?} → {:>12.3?}  {}",
+                base,
+                elapsed,
+                format!(
+                    "+{:.0}%",
+                    (elapsed.as_secs_f64() / base.as_secs_f64() - 1.) * 100.
+                )
+                .red()
+            ));
+        }
+    }
+}
+
+/// Run the solutions on a pool of threads, then the serial ones one after the other.
+///
+/// Returns the records in the same order as the solutions.
+fn run_parallel(
+    prepared: &[(&Solution, Result<Job, String>)],
+    options: &RunOptions,
+) -> Vec<Option<RunRecord>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+        .expect("failed to create the thread pool");
+
+    let mut records: Vec<Option<RunRecord>> = pool.install(|| {
+        prepared
+            .par_iter()
+            .map(|(sol, job)| match job {
+                Ok(job) if !options.is_serial(sol) => Some(run_solution(job, options)),
+                _ => None,
+            })
+            .collect()
+    });
+
+    // the timing-sensitive ones on an idle machine
+    for ((sol, job), record) in prepared.iter().zip(records.iter_mut()) {
+        if let Ok(job) = job
+            && options.is_serial(sol)
+        {
+            *record = Some(run_solution(job, options));
+        }
+    }
+
+    records
+}
+
 fn load_toml(content: &str) -> HashMap<(u16, u8), (String, String, String)> {
     let config: toml::Value = toml::from_str(content).expect("failed to parse TOML");
     let config = config.as_table().expect("TOML must be a table");
@@ -328,44 +1325,263 @@ fn load_toml(content: &str) -> HashMap<(u16, u8), (String, String, String)> {
     map
 }
 
-fn run_solution_from_file(
-    sol: &Solution,
-    input_txt: bool,
-    #[cfg(feature = "timingsdb")] db: Option<&dyn aor::rundb::TimingsDb>,
-) -> Option<(Duration, Duration, bool, bool)> {
+/// Options that drive how the solutions are executed.
+#[derive(Clone, Default)]
+struct RunOptions {
+    /// Number of worker threads, 0 means one per CPU.
+    jobs: usize,
+    /// Filters of the solutions that must run alone, after the parallel ones.
+    serial: Vec<(Option<u16>, Option<u8>)>,
+    /// Wall-clock limit of a solution.
+    timeout: Option<Duration>,
+    /// Number of warmup and timed runs in bench mode.
+    bench: Option<(usize, usize)>,
+    /// The only part to solve.
+    part: Option<u8>,
+}
+
+impl RunOptions {
+    fn from_args(args: &Args) -> Self {
+        Self {
+            jobs: option_value(args, "--jobs", 1),
+            serial: args
+                .option_value("--serial")
+                .map(|filters| filters.split(',').map(parse_filter).collect())
+                .unwrap_or_default(),
+            timeout: Duration::try_from_secs_f64(option_value(args, "--timeout", 0.0))
+                .ok()
+                .filter(|timeout| !timeout.is_zero()),
+            bench: (args.has_option("--bench") || args.command() == Some("bench")).then(|| {
+                (
+                    option_value(args, "--warmup", 3),
+                    option_value(args, "--runs", 10),
+                )
+            }),
+            part: match args.value_of::<u8>("--part") {
+                Ok(None) => None,
+                Ok(Some(part @ (1 | 2))) => Some(part),
+                _ => {
+                    eprintln!("invalid --part: expected 1 or 2");
+                    std::process::exit(2);
+                }
+            },
+        }
+    }
+
+    /// Return `true` if the solution is timing-sensitive and must run alone.
+    fn is_serial(&self, sol: &Solution) -> bool {
+        self.serial
+            .iter()
+            .any(|&(y, d)| y.is_none_or(|y| y == sol.year) && d.is_none_or(|d| d == sol.day))
+    }
+}
+
+/// A solution ready to run, with its input and expected answers.
+struct Job {
+    sol: Solution,
+    /// The user of the input, in multi-user mode.
+    user: Option<String>,
+    data: String,
+    answer1: String,
+    answer2: String,
+    source: String,
+    /// The only part with an expected answer, for the samples.
+    part: Option<u8>,
+    /// The issues of the input sanity checks.
+    issues: Vec<Issue>,
+}
+
+impl Job {
+    /// Check the input, then normalize it for the solution.
+    fn validate(self, validator: &mut Validator) -> Self {
+        let issues = validator.check(&self.sol, &self.data);
+        Self {
+            data: aoc::normalize_input(self.data),
+            issues,
+            ..self
+        }
+    }
+}
+
+/// Where the inputs of the solutions come from.
+enum Loader {
+    /// The default input of each puzzle.
+    Default,
+    /// A TOML file with the inputs and the answers.
+    Toml(HashMap<(u16, u8), (String, String, String)>, String),
+    /// The inputs of several users, `data/<user>/<year>/<day>.in`.
+    Users(Vec<(String, PathBuf)>),
+    /// The samples of the `samples.toml` manifests.
+    Samples,
+}
+
+impl Loader {
+    fn from_args(args: &Args) -> Self {
+        // load data from TOML if any
+        let toml_file = args.params().iter().find(|path| {
+            Path::new(path)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+        });
+
+        match toml_file {
+            Some(path) => {
+                let content = std::fs::read_to_string(path).expect("failed to read TOML file");
+                Self::Toml(load_toml(&content), path.clone())
+            }
+            None if args.has_option("--samples") => Self::Samples,
+            None if args.has_option("--users") => Self::Users(user_dirs(None)),
+            None => args
+                .option_value("--user")
+                .map_or(Self::Default, |user| Self::Users(user_dirs(Some(user)))),
+        }
+    }
+
+    /// Return the jobs of a solution, or the paths of the missing inputs.
+    fn jobs(&self, sol: &Solution) -> Vec<Result<Job, String>> {
+        match self {
+            Self::Default => vec![load_job(sol, false)],
+            Self::Toml(data, file) => data
+                .get(&(sol.year, sol.day))
+                .map(|(input, part1, part2)| Job {
+                    sol: sol.clone(),
+                    user: None,
+                    data: input.clone(),
+                    answer1: part1.clone(),
+                    answer2: part2.clone(),
+                    source: format!("({file} [{}.{}])", sol.year, sol.day),
+                    part: None,
+                    issues: Vec::new(),
+                })
+                .into_iter()
+                .map(Ok)
+                .collect(),
+            // users do not have inputs for all the puzzles: missing ones are not reported
+            Self::Users(users) => users
+                .iter()
+                .filter_map(|(user, dir)| {
+                    let path = dir
+                        .join(sol.year.to_string())
+                        .join(sol.day.to_string())
+                        .with_extension("in");
+                    let data = std::fs::read_to_string(&path).ok()?;
+                    let (answer1, answer2) =
+                        answers::read(&answers::answer_path(&path)).unwrap_or_default();
+
+                    Some(Ok(Job {
+                        sol: sol.clone(),
+                        user: Some(user.clone()),
+                        data,
+                        answer1,
+                        answer2,
+                        source: path.display().to_string(),
+                        part: None,
+                        issues: Vec::new(),
+                    }))
+                })
+                .collect(),
+            Self::Samples => sol
+                .samples
+                .iter()
+                .map(|sample| {
+                    Ok(Job {
+                        sol: sol.clone(),
+                        user: None,
+                        data: sample.data.to_string(),
+                        answer1: sample.part1.unwrap_or_default().to_string(),
+                        answer2: sample.part2.unwrap_or_default().to_string(),
+                        source: format!("{}.txt", sample.name),
+                        part: match (sample.part1, sample.part2) {
+                            (Some(_), None) => Some(1),
+                            (None, Some(_)) => Some(2),
+                            _ => None,
+                        },
+                        issues: Vec::new(),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Return the user directories of `data/`, sorted by name, optionally filtered like `runall.py --user`:
+/// `me` is the first user, `mine` the numeric ones, otherwise the exact name or the `-ID` suffix.
+fn user_dirs(filter: Option<&str>) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(root_dir().join("data")) else {
+        return vec![];
+    };
+
+    let users: Vec<_> = entries
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| Some((entry.file_name().into_string().ok()?, entry.path())))
+        .filter(|(user, _)| !user.starts_with('.'))
+        .sorted()
+        .collect();
+
+    match filter {
+        None => users,
+        Some("me") => users.into_iter().take(1).collect(),
+        Some("mine") => users
+            .into_iter()
+            .filter(|(user, _)| user.bytes().all(|c| c.is_ascii_digit()))
+            .collect(),
+        Some(id) => users
+            .into_iter()
+            .filter(|(user, _)| {
+                user == id
+                    || user
+                        .find('-')
+                        .is_some_and(|pos| user[pos + 1..].starts_with(id))
+            })
+            .collect(),
+    }
+}
+
+/// Load the input and the expected answers of a solution.
+///
+/// Returns the path of the missing input file on error.
+fn load_job(sol: &Solution, input_txt: bool) -> Result<Job, String> {
     let (path_input, path_answer) = find_input_path(sol, input_txt);
 
     if path_input.is_file()
         && let Ok(data) = std::fs::read_to_string(&path_input)
     {
-        let source = path_input.as_os_str().to_str().unwrap();
-
-        if let Ok(ok) = std::fs::read_to_string(path_answer) {
-            let (ok1, ok2) = ok.trim_ascii().split_once('\n').unwrap_or((&ok, ""));
-            return Some(run_solution(
-                sol,
-                &data,
-                ok1,
-                ok2,
-                source,
-                #[cfg(feature = "timingsdb")]
-                db,
-            ));
-        }
-        return Some(run_solution(
-            sol,
-            &data,
-            "",
-            "",
+        let source = path_input.as_os_str().to_str().unwrap().to_string();
+
+        let (answer1, answer2) = answers::read(&path_answer).unwrap_or_default();
+
+        return Ok(Job {
+            sol: sol.clone(),
+            user: None,
+            data,
+            answer1,
+            answer2,
             source,
-            #[cfg(feature = "timingsdb")]
-            db,
-        ));
+            part: None,
+            issues: Vec::new(),
+        });
     }
 
-    println!("  missing file: {}", path_input.to_str().unwrap().red());
+    // the input store, when there is no input file
+    if !input_txt
+        && let Ok(store) = InputStore::open(&root_dir().join("data").join(".store"))
+        && let Some(input) = store.find(sol.year, sol.day)
+        && let Ok(data) = store.read(input)
+    {
+        return Ok(Job {
+            sol: sol.clone(),
+            user: None,
+            data,
+            answer1: input.answer1.clone(),
+            answer2: input.answer2.clone(),
+            source: format!("store:{} ({})", input.short_digest(), input.source),
+            part: None,
+            issues: Vec::new(),
+        });
+    }
 
-    None
+    Err(path_input.to_str().unwrap().to_string())
 }
 
 fn find_input_path(sol: &Solution, input_txt: bool) -> (PathBuf, PathBuf) {
@@ -421,93 +1637,96 @@ fn find_input_path(sol: &Solution, input_txt: bool) -> (PathBuf, PathBuf) {
         path = hint;
     }
 
-    if path.file_name() == Path::new("input.txt").file_name() {
-        (path.clone(), path.with_file_name("answer.txt"))
-    } else {
-        (path.clone(), path.with_extension("ok"))
-    }
+    let answer = answers::answer_path(&path);
+    (path, answer)
 }
 
-/// Executes a solution and records its timing in the database.
+/// Executes a solution, or only one of its parts with `--part`, unless its input is invalid.
 ///
-/// Returns a tuple containing:
-/// - The elapsed time of this execution.
-/// - The best elapsed time recorded in the database so far.
-/// - A boolean indicating if the solution was correct.
-/// - A boolean indicating if the solution failed.
-fn run_solution(
-    sol: &Solution,
-    input_txt: &str,
-    answer1: &str,
-    answer2: &str,
-    source: &str,
-    #[cfg(feature = "timingsdb")] db: Option<&dyn aor::rundb::TimingsDb>,
-) -> (Duration, Duration, bool, bool) {
-    if let Some(alt) = &sol.alt {
-        println!(
-            "{} day {} ({}): {}",
-            sol.year,
-            sol.day,
-            alt.magenta(),
-            source.italic().dimmed()
-        );
-    } else {
-        println!("{} day {}: {}", sol.year, sol.day, source.italic().dimmed());
-    }
-
-    // run the solution
-    let instant = Instant::now();
-    let (part1, part2) = (sol.solve)(input_txt);
-    let elapsed = instant.elapsed();
-
-    let mut success = false;
-    let mut failed = false;
+/// Returns the record of the run, the best elapsed time being the one of this execution,
+/// or the median one in bench mode.
+fn run_solution(job: &Job, options: &RunOptions) -> RunRecord {
+    let part = options.part.or(job.part);
+
+    // the answer of the other part is not checked
+    let answer1 = if part == Some(2) { "" } else { &job.answer1 };
+    let answer2 = if part == Some(1) { "" } else { &job.answer2 };
+
+    let record = |part1, part2, elapsed| RunRecord {
+        user: job.user.clone(),
+        part,
+        warnings: job
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+            .map(|issue| issue.message.clone())
+            .collect(),
+        ..RunRecord::new(
+            &job.sol,
+            part1,
+            part2,
+            answer1,
+            answer2,
+            elapsed,
+            elapsed,
+            &job.source,
+        )
+    };
 
-    let micros = Duration::new(elapsed.as_secs(), elapsed.subsec_micros() * 1000);
+    if let Some(error) = job
+        .issues
+        .iter()
+        .find(|issue| issue.severity == Severity::Error)
+    {
+        return record(String::new(), String::new(), Duration::ZERO)
+            .aborted(Status::Invalid, error.message.clone());
+    }
 
-    if answer1.is_empty() {
-        print_part_result(1, &part1, "", sol.year, sol.day);
-        print_part_result(2, &part2, "", sol.year, sol.day);
-    } else {
-        print_part_result(1, &part1, answer1, sol.year, sol.day);
-        print_part_result(2, &part2, answer2, sol.year, sol.day);
+    let run = || aor::guard::run_parts(&job.sol, part, &job.data, options.timeout);
 
-        if answer1.trim_ascii() == part1 && answer2.trim_ascii() == part2 {
-            success = true;
+    // run the solution, once or many times
+    let (outcome, elapsed, phases, stats, bench_phases) =
+        if let Some((warmup, runs)) = options.bench {
+            let (outcome, stats, bench_phases) = aor::bench::run(run, warmup, runs);
+            let elapsed = stats.map_or(Duration::ZERO, |stats| stats.median);
+            let phases = bench_phases.map(|stats| stats.median());
+            (outcome, elapsed, phases, stats, bench_phases)
         } else {
-            failed = true;
-        }
-    }
-
-    #[cfg(feature = "timingsdb")]
-    let best_elapsed = if let Some(db) = db {
-        db.update(sol.year, sol.day, input_txt, elapsed)
-            .unwrap_or(elapsed)
-    } else {
-        elapsed
+            let (outcome, elapsed, phases) = run();
+            (outcome, elapsed, phases, None, None)
+        };
+
+    let record = |part1, part2| RunRecord {
+        bench: stats,
+        bench_phases,
+        phases,
+        ..record(part1, part2, elapsed)
     };
-    #[cfg(not(feature = "timingsdb"))]
-    let best_elapsed = elapsed;
 
-    if best_elapsed < elapsed {
-        println!(
-            "{}",
-            format!("  Elapsed : {micros:#?} (best: {best_elapsed:#?})")
-                .italic()
-                .dimmed()
-        );
-    } else {
-        println!("{}", format!("  Elapsed : {micros:#?}").italic());
+    match outcome {
+        Outcome::Answers(part1, part2) => record(part1, part2),
+        Outcome::Panic(msg) => record(String::new(), String::new()).aborted(Status::Panic, msg),
+        Outcome::Timeout(timeout) => record(String::new(), String::new())
+            .aborted(Status::Timeout, format!("no answer after {timeout:?}")),
     }
-    println!();
-
-    (elapsed, best_elapsed, success, failed)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_single_day_options() {
+        // the command line of scripts/runall.py
+        let args = Args::try_parse_from(&SPEC, ["-r", "2015:1", "input.txt", "--elapsed"]).unwrap();
+        assert!(args.has_option("-r"));
+        assert!(args.has_option("--elapsed"));
+        assert_eq!(args.params(), ["2015:1", "input.txt"]);
+
+        let args = Args::try_parse_from(&SPEC, ["-rv", "2015:1", "input.txt"]).unwrap();
+        assert!(args.is_verbose());
+    }
+
     #[test]
     fn test_load_toml() {
         let toml_content = r#"
@@ -0,0 +1,352 @@
+//! Manifests of the runs, to know exactly what ran and to replay it on another machine or build.
+//!
+//! A manifest records the build (commit, profile, features, compiler) and the machine, then for each run
+//! the solution, the digest of its input, its answers and its elapsed time.
+
+use crate::Solution;
+use crate::inputs::InputStore;
+use crate::report::RunRecord;
+use crate::timings::{BuildInfo, digest, timestamp};
+use std::error::Error;
+use std::path::Path;
+use std::time::Duration;
+
+/// A run of a manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestRun {
+    pub year: u16,
+    pub day: u8,
+    pub alt: Option<String>,
+    pub user: Option<String>,
+    /// The only part solved, with `--part` or for a sample.
+    pub part: Option<u8>,
+    pub source: String,
+    /// The SHA-256 of the input, like in the timings databases.
+    pub digest: String,
+    pub part1: String,
+    pub part2: String,
+    pub status: String,
+    pub elapsed: Duration,
+}
+
+impl ManifestRun {
+    /// Name of the puzzle, like [`RunRecord::name`].
+    #[must_use]
+    pub fn name(&self) -> String {
+        let name = self.alt.as_ref().map_or_else(
+            || format!("{} day {}", self.year, self.day),
+            |alt| format!("{} day {} ({alt})", self.year, self.day),
+        );
+        match &self.user {
+            Some(user) => format!("{name} [{user}]"),
+            None => name,
+        }
+    }
+
+    /// Find the input of the run: its source file, a sample of the solution, or the input store,
+    /// provided it still has the same digest.
+    #[must_use]
+    pub fn find_input(&self, sol: &Solution, store: Option<&InputStore>) -> Option<String> {
+        let matches = |data: &String| digest(data) == self.digest;
+
+        std::fs::read_to_string(&self.source)
+            .ok()
+            .filter(matches)
+            .or_else(|| {
+                sol.samples
+                    .iter()
+                    .find(|sample| format!("{}.txt", sample.name) == self.source)
+                    .map(|sample| sample.data.to_string())
+                    .filter(matches)
+            })
+            .or_else(|| {
+                let store = store?;
+                let input = store
+                    .inputs()
+                    .into_iter()
+                    .find(|input| input.digest == self.digest)?;
+                store.read(input).ok()
+            })
+    }
+
+    /// Compare a run of the replay with this one.
+    ///
+    /// Returns whether the answers are the same, and a line that describes the differences.
+    #[must_use]
+    pub fn compare(&self, record: &RunRecord) -> (bool, String) {
+        // the aborted runs must fail the same way, the other ones give the same answers
+        let aborted = |status: &str| matches!(status, "timeout" | "panic" | "invalid");
+        let status = record.status.as_str();
+        let same = (record.part1.as_str(), record.part2.as_str())
+            == (self.part1.as_str(), self.part2.as_str())
+            && (status == self.status || !(aborted(status) || aborted(&self.status)));
+
+        let answers = match &record.error {
+            Some(_) if same => format!("same {status}"),
+            Some(error) => format!("{status} ({error}), was {}", self.status),
+            None if same => "same answers".to_string(),
+            None => format!(
+                "answers {} {}, was {} {}",
+                record.part1, record.part2, self.part1, self.part2
+            ),
+        };
+
+        let elapsed = if self.elapsed.is_zero() || record.error.is_some() {
+            String::new()
+        } else {
+            format!(
+                ", elapsed {:.3?} → {:.3?} ({:+.0}%)",
+                self.elapsed,
+                record.elapsed,
+                (record.elapsed.as_secs_f64() / self.elapsed.as_secs_f64() - 1.) * 100.
+            )
+        };
+
+        (same, format!("{answers}{elapsed}"))
+    }
+}
+
+/// The build, the machine and the runs of a `run_all`.
+#[derive(Clone, Debug)]
+pub struct Manifest {
+    pub build: BuildInfo,
+    /// When the runs happened, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub runs: Vec<ManifestRun>,
+}
+
+impl Manifest {
+    #[must_use]
+    pub fn new(build: BuildInfo) -> Self {
+        Self {
+            build,
+            timestamp: timestamp().unwrap_or_default(),
+            runs: Vec::new(),
+        }
+    }
+
+    /// Add the record of a run with its input.
+    pub fn add(&mut self, data: &str, record: &RunRecord) {
+        self.runs.push(ManifestRun {
+            year: record.year,
+            day: record.day,
+            alt: record.alt.clone(),
+            user: record.user.clone(),
+            part: record.part,
+            source: record.source.clone(),
+            digest: digest(data),
+            part1: record.part1.clone(),
+            part2: record.part2.clone(),
+            status: record.status.as_str().to_string(),
+            elapsed: record.bench.map_or(record.elapsed, |stats| stats.median),
+        });
+    }
+
+    /// Write the manifest as JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn write(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let runs: Vec<_> = self
+            .runs
+            .iter()
+            .map(|run| {
+                serde_json::json!({
+                    "year": run.year,
+                    "day": run.day,
+                    "alt": run.alt,
+                    "user": run.user,
+                    "part": run.part,
+                    "source": run.source,
+                    "digest": run.digest,
+                    "part1": run.part1,
+                    "part2": run.part2,
+                    "status": run.status,
+                    "elapsed_ns": u64::try_from(run.elapsed.as_nanos()).unwrap_or(u64::MAX),
+                })
+            })
+            .collect();
+
+        let doc = serde_json::json!({
+            "build": {
+                "commit": self.build.commit,
+                "profile": self.build.profile,
+                "features": self.build.features,
+                "rustc": self.build.rustc,
+                "host": self.build.host,
+                "cpu": self.build.cpu,
+            },
+            "timestamp": self.timestamp,
+            "runs": runs,
+        });
+
+        std::fs::write(path, serde_json::to_string_pretty(&doc)?)?;
+        Ok(())
+    }
+
+    /// Read a manifest written by [`Manifest::write`].
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid manifest.
+    pub fn read(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let doc: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        let field = |v: &serde_json::Value, name: &str| -> Result<u64, Box<dyn Error>> {
+            v[name]
+                .as_u64()
+                .ok_or_else(|| format!("missing field {name}").into())
+        };
+        let text = |v: &serde_json::Value, name: &str| -> String {
+            v[name].as_str().unwrap_or_default().to_string()
+        };
+
+        let build = &doc["build"];
+        let build = BuildInfo {
+            commit: text(build, "commit"),
+            profile: text(build, "profile"),
+            host: text(build, "host"),
+            features: build["features"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|feature| Some(feature.as_str()?.to_string()))
+                .collect(),
+            rustc: text(build, "rustc"),
+            cpu: text(build, "cpu"),
+        };
+
+        let mut runs = Vec::new();
+        for v in doc["runs"].as_array().into_iter().flatten() {
+            runs.push(ManifestRun {
+                year: u16::try_from(field(v, "year")?)?,
+                day: u8::try_from(field(v, "day")?)?,
+                alt: v["alt"].as_str().map(str::to_string),
+                user: v["user"].as_str().map(str::to_string),
+                part: v["part"].as_u64().map(u8::try_from).transpose()?,
+                source: text(v, "source"),
+                digest: text(v, "digest"),
+                part1: text(v, "part1"),
+                part2: text(v, "part2"),
+                status: text(v, "status"),
+                elapsed: Duration::from_nanos(field(v, "elapsed_ns")?),
+            });
+        }
+
+        Ok(Self {
+            build,
+            timestamp: field(&doc, "timestamp")?,
+            runs,
+        })
+    }
+
+    /// Describe the differences between the build and machine of the manifest and another one.
+    #[must_use]
+    pub fn build_changes(&self, build: &BuildInfo) -> Vec<String> {
+        let old = &self.build;
+
+        [
+            ("commit", &old.commit, &build.commit),
+            ("profile", &old.profile, &build.profile),
+            ("rustc", &old.rustc, &build.rustc),
+            ("host", &old.host, &build.host),
+            ("cpu", &old.cpu, &build.cpu),
+        ]
+        .into_iter()
+        .map(|(name, old, new)| (name, old.clone(), new.clone()))
+        .chain([("features", old.features.join(","), build.features.join(","))])
+        .filter(|(_, old, new)| old != new)
+        .map(|(name, old, new)| format!("{name}: {old} → {new}"))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Status;
+
+    fn build(cpu: &str) -> BuildInfo {
+        BuildInfo {
+            commit: "abc".to_string(),
+            profile: "release".to_string(),
+            host: "test".to_string(),
+            features: vec!["ascii".to_string()],
+            rustc: "rustc 1.90.0".to_string(),
+            cpu: cpu.to_string(),
+        }
+    }
+
+    fn record(part1: &str, elapsed: Duration) -> RunRecord {
+        RunRecord {
+            year: 2015,
+            day: 1,
+            alt: None,
+            user: None,
+            part1: part1.to_string(),
+            part2: "1795".to_string(),
+            answer1: String::new(),
+            answer2: String::new(),
+            status: Status::Unchecked,
+            error: None,
+            warnings: Vec::new(),
+            elapsed,
+            best: elapsed,
+            source: "input/2015/1.in".to_string(),
+            bench: None,
+            part: None,
+            phases: None,
+            bench_phases: None,
+        }
+    }
+
+    #[test]
+    fn test_write_read() {
+        let mut manifest = Manifest::new(build("cpu"));
+        manifest.add("(()", &record("74", Duration::from_millis(2)));
+
+        let path = std::env::temp_dir().join(format!("aor-manifest-{}.json", std::process::id()));
+        manifest.write(&path).unwrap();
+        let read = Manifest::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.runs, manifest.runs);
+        assert_eq!(read.runs[0].digest, digest("(()\n"));
+        assert_eq!(read.build.features, ["ascii"]);
+        assert_eq!(read.build_changes(&build("other")), ["cpu: cpu → other"]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut manifest = Manifest::new(build("cpu"));
+        manifest.add("(()", &record("74", Duration::from_millis(2)));
+        let run = &manifest.runs[0];
+
+        assert_eq!(
+            run.compare(&record("74", Duration::from_millis(3))),
+            (
+                true,
+                "same answers, elapsed 2.000ms → 3.000ms (+50%)".to_string()
+            )
+        );
+        assert_eq!(
+            run.compare(&record("75", Duration::from_millis(1))),
+            (
+                false,
+                "answers 75 1795, was 74 1795, elapsed 2.000ms → 1.000ms (-50%)".to_string()
+            )
+        );
+
+        let panic = record("", Duration::ZERO).aborted(Status::Panic, "boom".to_string());
+        assert_eq!(
+            run.compare(&panic),
+            (false, "panic (boom), was unchecked".to_string())
+        );
+
+        let mut manifest = Manifest::new(build("cpu"));
+        manifest.add("(()", &panic);
+        assert_eq!(
+            manifest.runs[0].compare(&panic),
+            (true, "same panic".to_string())
+        );
+    }
+}
@@ -0,0 +1,486 @@
+//! Markdown rendering of the timings, for the main README and the per-year READMEs.
+
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+/// Number of years per table in the main README.
+pub const YEARS_PER_TABLE: usize = 6;
+
+/// Format an elapsed time like `5 µs` or `24 ms`.
+#[must_use]
+pub fn fmt_elapsed(elapsed: Duration) -> String {
+    if elapsed < Duration::from_millis(1) {
+        format!("{} µs", elapsed.as_micros())
+    } else {
+        format!("{} ms", elapsed.as_millis())
+    }
+}
+
+/// Return the days whose time is the greatest of their year.
+fn slowest_days(timings: &BTreeMap<(u16, u8), Duration>) -> Vec<(u16, u8)> {
+    let mut slowest: BTreeMap<u16, (u8, Duration)> = BTreeMap::new();
+
+    for (&(year, day), &elapsed) in timings {
+        let e = slowest.entry(year).or_insert((day, elapsed));
+        if elapsed > e.1 {
+            *e = (day, elapsed);
+        }
+    }
+
+    slowest
+        .into_iter()
+        .map(|(year, (day, _))| (year, day))
+        .collect()
+}
+
+/// Render the day × year timings tables, `step` years per table, with the total of each year
+/// and the slowest day of each year in bold.
+#[must_use]
+pub fn timings_tables(timings: &BTreeMap<(u16, u8), Duration>, step: usize) -> String {
+    let years: Vec<u16> = timings.keys().map(|&(year, _)| year).dedup().collect();
+    let slowest = slowest_days(timings);
+    let last_day = timings.keys().map(|&(_, day)| day).max().unwrap_or(0);
+
+    let mut md = String::new();
+
+    for years in years.chunks(step.max(1)) {
+        let row = |first: &str, cells: Vec<String>| {
+            let mut line = format!("{first:>7}");
+            for cell in cells {
+                let _ = write!(line, " | {cell:>7}");
+            }
+            line.trim().to_string()
+        };
+
+        let _ = writeln!(
+            md,
+            "{}",
+            row("day", years.iter().map(u16::to_string).collect())
+        );
+        let _ = writeln!(
+            md,
+            "{}",
+            row(
+                "-------",
+                years.iter().map(|_| "-------".to_string()).collect()
+            )
+        );
+
+        for day in 1..=last_day {
+            let cells = years
+                .iter()
+                .map(|&year| {
+                    timings
+                        .get(&(year, day))
+                        .map_or_else(String::new, |&elapsed| {
+                            if slowest.contains(&(year, day)) {
+                                format!("**{}**", fmt_elapsed(elapsed))
+                            } else {
+                                fmt_elapsed(elapsed)
+                            }
+                        })
+                })
+                .collect();
+            let _ = writeln!(md, "{}", row(&day.to_string(), cells));
+        }
+
+        let totals = years
+            .iter()
+            .map(|&year| {
+                let total: Duration = timings
+                    .range((year, 0)..=(year, u8::MAX))
+                    .map(|(_, elapsed)| *elapsed)
+                    .sum();
+                format!("{:.3} s", total.as_secs_f64())
+            })
+            .collect();
+        let _ = writeln!(md, "{}", row("total", totals));
+        let _ = writeln!(md);
+    }
+
+    let total: Duration = timings.values().sum();
+    let _ = writeln!(md, "Total : {:.3} s", total.as_secs_f64());
+
+    md
+}
+
+/// Read back the timings of the tables rendered by [`timings_tables`], with their rounding.
+#[must_use]
+pub fn parse_timings_tables(md: &str) -> BTreeMap<(u16, u8), Duration> {
+    let mut timings = BTreeMap::new();
+    let mut years: Vec<Option<u16>> = Vec::new();
+
+    for line in md.lines() {
+        let mut cells = line.split('|').map(str::trim);
+        let first = cells.next().unwrap_or_default();
+
+        if first == "day" {
+            years = cells.map(|year| year.parse().ok()).collect();
+        } else if let Ok(day) = first.parse::<u8>() {
+            for (year, cell) in years.iter().zip(cells) {
+                let cell = cell.trim_matches('*');
+                let elapsed = cell
+                    .strip_suffix(" µs")
+                    .and_then(|n| n.parse().ok())
+                    .map(Duration::from_micros)
+                    .or_else(|| {
+                        cell.strip_suffix(" ms")
+                            .and_then(|n| n.parse().ok())
+                            .map(Duration::from_millis)
+                    });
+                if let (Some(year), Some(elapsed)) = (year, elapsed) {
+                    timings.insert((*year, day), elapsed);
+                }
+            }
+        }
+    }
+
+    timings
+}
+
+/// Return the content of the `## heading` section, up to the next `## ` heading.
+fn section(readme: &str, heading: &str) -> String {
+    readme
+        .lines()
+        .skip_while(|&line| line != heading)
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .fold(String::new(), |mut content, line| {
+            content.push_str(line);
+            content.push('\n');
+            content
+        })
+}
+
+/// Replace the content of the `## heading` section, up to the next `## ` heading.
+#[must_use]
+pub fn replace_section(readme: &str, heading: &str, content: &str) -> String {
+    let mut md = String::new();
+    let mut skip = false;
+
+    for line in readme.lines() {
+        if skip {
+            if line.starts_with("## ") {
+                skip = false;
+            } else {
+                continue;
+            }
+        }
+
+        md.push_str(line);
+        md.push('\n');
+
+        if line == heading {
+            skip = true;
+            md.push('\n');
+            md.push_str(content.trim_end());
+            md.push_str("\n\n");
+        }
+    }
+
+    md
+}
+
+/// Add or update the `Timing` column of the puzzle table of a year README.
+/// The days without a timing keep their cell.
+#[must_use]
+pub fn year_readme_timings(readme: &str, timings: &BTreeMap<u8, Duration>) -> String {
+    let mut md = String::new();
+    let mut has_column = false;
+
+    for line in readme.lines() {
+        let mut cells: Vec<String> = line.split(" | ").map(str::to_string).collect();
+
+        let value = if line.starts_with("Puzzle ") && cells.len() >= 3 {
+            has_column = cells[2].trim() == "Timing";
+            Some("Timing".to_string())
+        } else if line.starts_with("-----") && cells.len() >= 3 {
+            Some("-------".to_string())
+        } else {
+            line.strip_prefix("[Day ")
+                .and_then(|s| s.split_once(':'))
+                .and_then(|(day, _)| day.parse::<u8>().ok())
+                .filter(|_| cells.len() >= 3)
+                .and_then(|day| match timings.get(&day) {
+                    Some(&elapsed) => Some(fmt_elapsed(elapsed)),
+                    // keep the timing of a day that has not run
+                    None if has_column => None,
+                    None => Some(String::new()),
+                })
+        };
+
+        if let Some(value) = value {
+            let cell = format!("{value:>7}");
+            if has_column {
+                cells[2] = cell;
+            } else {
+                cells.insert(2, cell);
+            }
+        }
+
+        md.push_str(&cells.join(" | "));
+        md.push('\n');
+    }
+
+    md
+}
+
+/// The README of a new year, with an empty puzzle table.
+#[must_use]
+pub fn year_readme(year: u16) -> String {
+    format!(
+        "\
+# Advent of Code in Rust 🦀
+
+![AoC{year}](https://img.shields.io/badge/Advent_of_Code-{year}-8A2BE2)
+
+## {year} ([Calendar](https://adventofcode.com/{year})) ([Solutions](./)) : 0⭐
+
+Puzzle | Stars | Languages
+------ | ----- | -----------
+"
+    )
+}
+
+/// Add the row of a new day to the puzzle table of a year README, in the order of the days,
+/// or the link to its Rust solution if the day already has a row.
+#[must_use]
+pub fn year_readme_add_day(readme: &str, year: u16, day: u8, title: &str) -> String {
+    let link = format!("[![Rust](../../scripts/assets/rust.png)](day{day}/day{day}.rs)");
+
+    let day_of = |line: &str| {
+        line.strip_prefix("[Day ")
+            .and_then(|s| s.split_once(':'))
+            .and_then(|(day, _)| day.parse::<u8>().ok())
+    };
+
+    let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+
+    if let Some(line) = lines.iter_mut().find(|line| day_of(line) == Some(day)) {
+        if !line.contains(&format!("](day{day}/day{day}.rs)")) {
+            let (cells, languages) = line.rsplit_once(" | ").unwrap_or((line, ""));
+            *line = format!("{cells} | {link} {languages}")
+                .trim_end()
+                .to_string();
+        }
+    } else if let Some(header) = lines.iter().position(|line| line.starts_with("Puzzle ")) {
+        let header_cells: Vec<_> = lines[header].split(" | ").collect();
+        let width = header_cells[0].chars().count();
+
+        let mut cells = vec![
+            format!(
+                "{:<width$}",
+                format!("[Day {day}: {title}](https://adventofcode.com/{year}/day/{day})")
+            ),
+            " ".repeat(5),
+        ];
+        if header_cells.iter().any(|cell| cell.trim() == "Timing") {
+            cells.push(" ".repeat(7));
+        }
+        cells.push(link);
+
+        // after the separator and the rows of the previous days
+        let mut index = header + 2;
+        while index < lines.len() && day_of(&lines[index]).is_some_and(|d| d < day) {
+            index += 1;
+        }
+        lines.insert(index.min(lines.len()), cells.join(" | "));
+    }
+
+    let mut md = lines.join("\n");
+    md.push('\n');
+    md
+}
+
+/// Update the `Timings` section of the main README and the `Timing` column of the year READMEs.
+///
+/// The timings of the days that have not run, like in a filtered run, are kept.
+///
+/// # Errors
+/// Returns an error if a README cannot be read or written.
+pub fn update_readmes(root: &Path, timings: &BTreeMap<(u16, u8), Duration>) -> std::io::Result<()> {
+    let path = root.join("README.md");
+    let readme = std::fs::read_to_string(&path)?;
+
+    let mut merged = parse_timings_tables(&section(&readme, "## Timings"));
+    merged.extend(timings);
+    let tables = timings_tables(&merged, YEARS_PER_TABLE);
+    std::fs::write(&path, replace_section(&readme, "## Timings", &tables))?;
+
+    for (year, days) in &timings.iter().chunk_by(|((year, _), _)| *year) {
+        let days = days.map(|(&(_, day), &elapsed)| (day, elapsed)).collect();
+
+        let path = root
+            .join("src")
+            .join(format!("year{year}"))
+            .join("README.md");
+        if path.is_file() {
+            let readme = std::fs::read_to_string(&path)?;
+            std::fs::write(&path, year_readme_timings(&readme, &days))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_README: &str = "\
+## 2015
+
+Puzzle                        | Stars | Languages
+----------------------------- | ----- | -----------
+[Day 1: Not Quite Lisp](url1) | ⭐⭐  | [Rust](day1/day1.rs)
+[Day 2: I Was Told](url2)     | ⭐⭐  | [Rust](day2/day2.rs)
+";
+
+    #[test]
+    fn test_fmt_elapsed() {
+        assert_eq!(fmt_elapsed(Duration::from_nanos(5_400)), "5 µs");
+        assert_eq!(fmt_elapsed(Duration::from_micros(24_300)), "24 ms");
+        assert_eq!(fmt_elapsed(Duration::from_millis(3_954)), "3954 ms");
+    }
+
+    #[test]
+    fn test_timings_tables() {
+        let timings = BTreeMap::from([
+            ((2015, 1), Duration::from_micros(5)),
+            ((2015, 2), Duration::from_millis(63)),
+            ((2016, 1), Duration::from_millis(2)),
+        ]);
+
+        let md = timings_tables(&timings, 6);
+        let lines: Vec<_> = md.lines().collect();
+
+        assert_eq!(lines[0], "day |    2015 |    2016");
+        assert_eq!(lines[1], "------- | ------- | -------");
+        assert_eq!(lines[2], "1 |    5 µs | **2 ms**");
+        assert_eq!(lines[3], "2 | **63 ms** |");
+        assert_eq!(lines[4], "total | 0.063 s | 0.002 s");
+        assert_eq!(lines[6], "Total : 0.065 s");
+
+        // one table per year
+        assert_eq!(timings_tables(&timings, 1).matches("total").count(), 2);
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# title\n\n## Timings\n\nold\n\n## Next\n\ntext\n";
+        assert_eq!(
+            replace_section(readme, "## Timings", "new\n"),
+            "# title\n\n## Timings\n\nnew\n\n## Next\n\ntext\n"
+        );
+    }
+
+    #[test]
+    fn test_year_readme_add_day() {
+        let md = year_readme_add_day(YEAR_README, 2015, 3, "Perfectly Spherical Houses");
+        let lines: Vec<_> = md.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[6],
+            "[Day 3: Perfectly Spherical Houses](https://adventofcode.com/2015/day/3) |       | \
+             [![Rust](../../scripts/assets/rust.png)](day3/day3.rs)"
+        );
+
+        // in the order of the days, with the width of the column
+        let readme = YEAR_README.replace(
+            "[Day 2: I Was Told](url2)     | ⭐⭐  | [Rust](day2/day2.rs)\n",
+            "",
+        );
+        let md = year_readme_add_day(&readme, 2015, 2, "Two");
+        assert_eq!(
+            md.lines().nth(5).unwrap(),
+            "[Day 2: Two](https://adventofcode.com/2015/day/2) |       | \
+             [![Rust](../../scripts/assets/rust.png)](day2/day2.rs)"
+        );
+
+        // the row of a day with a solution in another language only
+        let readme = YEAR_README.replace("[Rust](day2/day2.rs)", "[Python](day2/day2.py)");
+        let md = year_readme_add_day(&readme, 2015, 2, "I Was Told");
+        assert_eq!(
+            md.lines().nth(5).unwrap(),
+            "[Day 2: I Was Told](url2)     | ⭐⭐  | \
+             [![Rust](../../scripts/assets/rust.png)](day2/day2.rs) [Python](day2/day2.py)"
+        );
+        assert_eq!(year_readme_add_day(&md, 2015, 2, "I Was Told"), md);
+
+        // a new year
+        let md = year_readme_add_day(&year_readme(2026), 2026, 1, "Title");
+        assert!(md.ends_with(
+            "\
+------ | ----- | -----------
+[Day 1: Title](https://adventofcode.com/2026/day/1) |       | \
+[![Rust](../../scripts/assets/rust.png)](day1/day1.rs)
+"
+        ));
+    }
+
+    #[test]
+    fn test_year_readme_timings() {
+        let timings = BTreeMap::from([(1, Duration::from_micros(21))]);
+
+        let md = year_readme_timings(YEAR_README, &timings);
+        let lines: Vec<_> = md.lines().collect();
+        assert_eq!(
+            lines[2],
+            "Puzzle                        | Stars |  Timing | Languages"
+        );
+        assert_eq!(
+            lines[3],
+            "----------------------------- | ----- | ------- | -----------"
+        );
+        assert_eq!(
+            lines[4],
+            "[Day 1: Not Quite Lisp](url1) | ⭐⭐  |   21 µs | [Rust](day1/day1.rs)"
+        );
+        assert_eq!(
+            lines[5],
+            "[Day 2: I Was Told](url2)     | ⭐⭐  |         | [Rust](day2/day2.rs)"
+        );
+
+        // updating keeps the timings of the other days
+        let timings = BTreeMap::from([(2, Duration::from_millis(3))]);
+        let md = year_readme_timings(&md, &timings);
+        let lines: Vec<_> = md.lines().collect();
+        assert_eq!(
+            lines[2],
+            "Puzzle                        | Stars |  Timing | Languages"
+        );
+        assert_eq!(
+            lines[4],
+            "[Day 1: Not Quite Lisp](url1) | ⭐⭐  |   21 µs | [Rust](day1/day1.rs)"
+        );
+        assert_eq!(
+            lines[5],
+            "[Day 2: I Was Told](url2)     | ⭐⭐  |    3 ms | [Rust](day2/day2.rs)"
+        );
+        assert_eq!(year_readme_timings(&md, &timings), md);
+    }
+
+    #[test]
+    fn test_parse_timings_tables() {
+        let timings = BTreeMap::from([
+            ((2015, 1), Duration::from_micros(5)),
+            ((2015, 2), Duration::from_millis(63)),
+            ((2016, 1), Duration::from_millis(2)),
+            ((2024, 25), Duration::from_millis(1234)),
+        ]);
+
+        assert_eq!(parse_timings_tables(&timings_tables(&timings, 2)), timings);
+
+        let readme = format!(
+            "# AoC\n\n## Timings\n\n{}\n## Next\n\n1 | 2 ms\n",
+            timings_tables(&timings, 6)
+        );
+        assert_eq!(
+            parse_timings_tables(&section(&readme, "## Timings")),
+            timings
+        );
+    }
+}
@@ -0,0 +1,1002 @@
+//! Reporting of the solution runs, either as colored text or in a machine-readable format.
+
+use crate::Solution;
+use crate::bench::{PhaseStats, Stats};
+use crate::guard::Phases;
+use crate::markdown;
+use colored::Colorize;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Output format of the runner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Jsonl,
+    Csv,
+    Junit,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "jsonl" => Ok(Self::Jsonl),
+            "csv" => Ok(Self::Csv),
+            "junit" | "xml" => Ok(Self::Junit),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "unknown format: {s} (expected text, json, jsonl, csv, junit or markdown)"
+            )),
+        }
+    }
+}
+
+impl Format {
+    /// Create the reporter for this format.
+    #[must_use]
+    pub fn reporter(self) -> Box<dyn Reporter> {
+        match self {
+            Self::Text => Box::new(TextReporter),
+            Self::Json => Box::new(JsonReporter::default()),
+            Self::Jsonl => Box::new(JsonlReporter),
+            Self::Csv => Box::new(CsvReporter::default()),
+            Self::Junit => Box::new(JunitReporter::default()),
+            Self::Markdown => Box::new(MarkdownReporter::default()),
+        }
+    }
+}
+
+/// Outcome of a solution run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Both answers match the expected ones.
+    Success,
+    /// At least one answer differs from the expected ones.
+    Failed,
+    /// No expected answers to compare with.
+    Unchecked,
+    /// The solution did not finish in time.
+    Timeout,
+    /// The solution panicked.
+    Panic,
+    /// The input did not pass the sanity checks, the solution was not run.
+    Invalid,
+}
+
+impl Status {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::Unchecked => "unchecked",
+            Self::Timeout => "timeout",
+            Self::Panic => "panic",
+            Self::Invalid => "invalid",
+        }
+    }
+}
+
+/// The result of running one solution against one input.
+#[derive(Clone, Debug)]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub alt: Option<String>,
+    /// The user of the input, when running the inputs of several users.
+    pub user: Option<String>,
+    pub part1: String,
+    pub part2: String,
+    pub answer1: String,
+    pub answer2: String,
+    pub status: Status,
+    /// The panic message, the timeout reason or the input error.
+    pub error: Option<String>,
+    /// The warnings of the input sanity checks.
+    pub warnings: Vec<String>,
+    pub elapsed: Duration,
+    pub best: Duration,
+    pub source: String,
+    /// The distribution of the elapsed times in bench mode.
+    pub bench: Option<Stats>,
+    /// The only part solved, with `--part`.
+    pub part: Option<u8>,
+    /// The elapsed times of the parsing and of each part, if the solution has a `parse` callback.
+    /// In bench mode, the median ones.
+    pub phases: Option<Phases>,
+    /// The distributions of the elapsed times of the phases in bench mode.
+    pub bench_phases: Option<PhaseStats>,
+}
+
+impl RunRecord {
+    /// Build a record and compute its status from the answers.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sol: &Solution,
+        part1: String,
+        part2: String,
+        answer1: &str,
+        answer2: &str,
+        elapsed: Duration,
+        best: Duration,
+        source: &str,
+    ) -> Self {
+        let status = if answer1.is_empty() && answer2.is_empty() {
+            Status::Unchecked
+        } else if answer1.trim_ascii() == part1 && answer2.trim_ascii() == part2 {
+            Status::Success
+        } else {
+            Status::Failed
+        };
+
+        Self {
+            year: sol.year,
+            day: sol.day,
+            alt: sol.alt.clone(),
+            user: None,
+            part1,
+            part2,
+            answer1: answer1.trim_ascii().to_string(),
+            answer2: answer2.trim_ascii().to_string(),
+            status,
+            error: None,
+            warnings: Vec::new(),
+            elapsed,
+            best,
+            source: source.to_string(),
+            bench: None,
+            part: None,
+            phases: None,
+            bench_phases: None,
+        }
+    }
+
+    /// Mark the record as aborted by a timeout, a panic or an invalid input.
+    #[must_use]
+    pub fn aborted(self, status: Status, error: String) -> Self {
+        Self {
+            status,
+            error: Some(error),
+            ..self
+        }
+    }
+
+    /// Name of the puzzle, like `2015 day 1`, `2016 day 5 (orig)` or `2017 day 3 [alice]`.
+    #[must_use]
+    pub fn name(&self) -> String {
+        let name = self.alt.as_ref().map_or_else(
+            || format!("{} day {}", self.year, self.day),
+            |alt| format!("{} day {} ({alt})", self.year, self.day),
+        );
+        match &self.user {
+            Some(user) => format!("{name} [{user}]"),
+            None => name,
+        }
+    }
+
+    /// Return `true` if the record has a timing meaningful for the README tables,
+    /// i.e. the main solution of the day ran both parts until the end with the default input.
+    #[must_use]
+    pub const fn is_readme_timing(&self) -> bool {
+        self.alt.is_none() && self.user.is_none() && self.part.is_none() && self.error.is_none()
+    }
+
+    pub(crate) fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "solution",
+            "year": self.year,
+            "day": self.day,
+            "alt": self.alt,
+            "user": self.user,
+            "part1": self.part1,
+            "part2": self.part2,
+            "answer1": self.answer1,
+            "answer2": self.answer2,
+            "status": self.status.as_str(),
+            "error": self.error,
+            "warnings": self.warnings,
+            "elapsed_ns": nanos(self.elapsed),
+            "best_ns": nanos(self.best),
+            "source": self.source,
+            "bench": self.bench.map(stats_json),
+            "part": self.part,
+            "phases": self.phases.map(|p| serde_json::json!({
+                "parse_ns": nanos(p.parse),
+                "part1_ns": p.part1.map(nanos),
+                "part2_ns": p.part2.map(nanos),
+            })),
+            "bench_phases": self.bench_phases.map(|p| serde_json::json!({
+                "parse": stats_json(p.parse),
+                "part1": p.part1.map(stats_json),
+                "part2": p.part2.map(stats_json),
+            })),
+        })
+    }
+}
+
+fn stats_json(b: Stats) -> serde_json::Value {
+    serde_json::json!({
+        "runs": b.runs,
+        "min_ns": nanos(b.min),
+        "median_ns": nanos(b.median),
+        "mean_ns": nanos(b.mean),
+        "stddev_ns": nanos(b.stddev),
+        "p95_ns": nanos(b.p95),
+    })
+}
+
+/// Totals of a run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Summary {
+    pub puzzles: u32,
+    pub success: u32,
+    pub failed: u32,
+    pub timeout: u32,
+    pub panic: u32,
+    pub invalid: u32,
+    pub elapsed: Duration,
+    pub best: Duration,
+    /// The best times come from a timings database.
+    pub timed: bool,
+}
+
+impl Summary {
+    /// Account for a new record.
+    pub fn add(&mut self, record: &RunRecord) {
+        self.puzzles += 1;
+        self.elapsed += record.elapsed;
+        self.best += record.best;
+        match record.status {
+            Status::Success => self.success += 1,
+            Status::Failed => self.failed += 1,
+            Status::Timeout => self.timeout += 1,
+            Status::Panic => self.panic += 1,
+            Status::Invalid => self.invalid += 1,
+            Status::Unchecked => (),
+        }
+    }
+
+    fn to_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "summary",
+            "puzzles": self.puzzles,
+            "success": self.success,
+            "failed": self.failed,
+            "timeout": self.timeout,
+            "panic": self.panic,
+            "invalid": self.invalid,
+            "elapsed_ns": nanos(self.elapsed),
+            "best_ns": nanos(self.best),
+        })
+    }
+}
+
+const fn round_micros(d: Duration) -> Duration {
+    Duration::new(d.as_secs(), d.subsec_micros() * 1000)
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Receive the events of a run and render them.
+pub trait Reporter {
+    /// Called once before any solution is run.
+    fn begin(&mut self) {}
+
+    /// Called just before running a solution.
+    fn start(&mut self, _sol: &Solution, _source: &str) {}
+
+    /// Called when the input of a solution cannot be found.
+    fn missing(&mut self, _sol: &Solution, _path: &str) {}
+
+    /// Called with the result of a solution.
+    fn record(&mut self, record: &RunRecord);
+
+    /// Called once at the end of the run.
+    fn end(&mut self, summary: &Summary);
+}
+
+/// The historical colored output.
+pub struct TextReporter;
+
+impl TextReporter {
+    /// The distribution of a benchmark, like `  Bench   : min 1ms  median 2ms ...`.
+    fn bench_line(name: &str, b: &Stats) -> String {
+        format!(
+            "  {name:<8}: min {:#?}  median {:#?}  mean {:#?} ± {:#?}  p95 {:#?}  ({} runs)",
+            round_micros(b.min),
+            round_micros(b.median),
+            round_micros(b.mean),
+            round_micros(b.stddev),
+            round_micros(b.p95),
+            b.runs
+        )
+    }
+
+    fn print_part_result(part: u8, answer: &str, ok: &str, year: u16, day: u8) {
+        if part == 2 && (day == 25 || (year >= 2025 && day == 12)) {
+            println!(
+                "  {}  : {}",
+                "Part 2".yellow(),
+                "n/a".dimmed() // "Merry Christmas".bright_blue()
+            );
+        } else {
+            print!("  {}  : ", format!("Part {part}").yellow());
+            if ok.is_empty() {
+                println!("{answer}");
+            } else if answer.trim_ascii() == ok.trim_ascii() {
+                println!("{}", answer.bright_green());
+            } else {
+                println!("{}", answer.bright_red());
+            }
+        }
+    }
+}
+
+impl Reporter for TextReporter {
+    fn begin(&mut self) {
+        println!("💫 {} 🎄✨ 💫", "Advent of Code".green());
+        println!();
+    }
+
+    fn start(&mut self, sol: &Solution, source: &str) {
+        if let Some(alt) = &sol.alt {
+            println!(
+                "{} day {} ({}): {}",
+                sol.year,
+                sol.day,
+                alt.magenta(),
+                source.italic().dimmed()
+            );
+        } else {
+            println!("{} day {}: {}", sol.year, sol.day, source.italic().dimmed());
+        }
+    }
+
+    fn missing(&mut self, _sol: &Solution, path: &str) {
+        println!("  missing file: {}", path.red());
+    }
+
+    fn record(&mut self, record: &RunRecord) {
+        for warning in &record.warnings {
+            println!("  {} : {}", "Warning".yellow(), warning.yellow());
+        }
+
+        match record.status {
+            Status::Timeout | Status::Panic | Status::Invalid => {
+                println!(
+                    "  {} : {}",
+                    record.status.as_str().to_uppercase().bright_red().bold(),
+                    record.error.as_deref().unwrap_or_default().red()
+                );
+                println!();
+                return;
+            }
+            Status::Success | Status::Failed | Status::Unchecked => (),
+        }
+
+        // without expected answers, part 2 is printed uncolored too
+        let answer2 = if record.status == Status::Unchecked {
+            ""
+        } else {
+            &record.answer2
+        };
+
+        if record.part != Some(2) {
+            Self::print_part_result(1, &record.part1, &record.answer1, record.year, record.day);
+        }
+        if record.part != Some(1) {
+            Self::print_part_result(2, &record.part2, answer2, record.year, record.day);
+        }
+
+        let elapsed = record.elapsed;
+        let best_elapsed = record.best;
+        let micros = round_micros(elapsed);
+
+        if best_elapsed < elapsed {
+            println!(
+                "{}",
+                format!("  Elapsed : {micros:#?} (best: {best_elapsed:#?})")
+                    .italic()
+                    .dimmed()
+            );
+        } else {
+            println!("{}", format!("  Elapsed : {micros:#?}").italic());
+        }
+
+        if let Some(phases) = record.phases {
+            let mut line = format!("  Phases  : parse {:#?}", round_micros(phases.parse));
+            for (part, elapsed) in [(1, phases.part1), (2, phases.part2)] {
+                if let Some(elapsed) = elapsed {
+                    let _ = write!(line, ", part {part} {:#?}", round_micros(elapsed));
+                }
+            }
+            println!("{}", line.italic().dimmed());
+        }
+
+        if let Some(b) = record.bench {
+            println!("{}", Self::bench_line("Bench", &b).italic());
+        }
+        if let Some(p) = record.bench_phases {
+            println!("{}", Self::bench_line("Parse", &p.parse).italic().dimmed());
+            for (name, stats) in [("Part 1", p.part1), ("Part 2", p.part2)] {
+                if let Some(stats) = stats {
+                    println!("{}", Self::bench_line(name, &stats).italic().dimmed());
+                }
+            }
+        }
+        println!();
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        let Summary {
+            puzzles,
+            success,
+            failed,
+            timeout,
+            panic,
+            invalid,
+            elapsed,
+            best,
+            timed,
+        } = *summary;
+
+        if puzzles > 1 {
+            println!();
+
+            if timed {
+                println!(
+                    "Elapsed: {:.6}s for {puzzles} puzzle(s) - {} - success: {success}, failed: {failed}, timeout: {timeout}, panic: {panic}, invalid: {invalid}",
+                    elapsed.as_secs_f64(),
+                    format!("best: {:.6}s", best.as_secs_f64()).bold(),
+                );
+            } else {
+                println!(
+                    "Elapsed: {:.6}s for {puzzles} puzzle(s) - success: {success}, failed: {failed}, timeout: {timeout}, panic: {panic}, invalid: {invalid}",
+                    elapsed.as_secs_f64(),
+                );
+            }
+        }
+    }
+}
+
+/// A single JSON document with all records and the summary.
+#[derive(Default)]
+pub struct JsonReporter {
+    records: Vec<serde_json::Value>,
+}
+
+impl Reporter for JsonReporter {
+    fn record(&mut self, record: &RunRecord) {
+        self.records.push(record.to_json());
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        let doc = serde_json::json!({
+            "solutions": self.records,
+            "summary": summary.to_json(),
+        });
+        println!("{doc:#}");
+    }
+}
+
+/// One JSON object per line, the last one being the summary.
+pub struct JsonlReporter;
+
+impl Reporter for JsonlReporter {
+    fn record(&mut self, record: &RunRecord) {
+        println!("{}", record.to_json());
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        println!("{}", summary.to_json());
+    }
+}
+
+/// Comma-separated values with a header line and a final `total` row.
+#[derive(Default)]
+pub struct CsvReporter {
+    header: bool,
+}
+
+const CSV_HEADER: &str = "year,day,alt,user,part1,part2,answer1,answer2,status,error,elapsed_ns,best_ns,source,runs,min_ns,median_ns,mean_ns,stddev_ns,p95_ns,part,parse_ns,part1_ns,part2_ns";
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl CsvReporter {
+    fn header(&mut self) {
+        if !self.header {
+            self.header = true;
+            println!("{CSV_HEADER}");
+        }
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn begin(&mut self) {
+        self.header();
+    }
+
+    fn record(&mut self, record: &RunRecord) {
+        self.header();
+        println!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            csv_field(record.alt.as_deref().unwrap_or_default()),
+            csv_field(record.user.as_deref().unwrap_or_default()),
+            csv_field(&record.part1),
+            csv_field(&record.part2),
+            csv_field(&record.answer1),
+            csv_field(&record.answer2),
+            record.status.as_str(),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+            nanos(record.elapsed),
+            nanos(record.best),
+            csv_field(&record.source),
+            record.bench.map_or_else(
+                || ",,,,,".to_string(),
+                |b| format!(
+                    "{},{},{},{},{},{}",
+                    b.runs,
+                    nanos(b.min),
+                    nanos(b.median),
+                    nanos(b.mean),
+                    nanos(b.stddev),
+                    nanos(b.p95)
+                )
+            ),
+            record.part.map(|part| part.to_string()).unwrap_or_default(),
+            record.phases.map_or_else(
+                || ",,".to_string(),
+                |p| format!(
+                    "{},{},{}",
+                    nanos(p.parse),
+                    p.part1.map(|d| nanos(d).to_string()).unwrap_or_default(),
+                    p.part2.map(|d| nanos(d).to_string()).unwrap_or_default(),
+                )
+            ),
+        );
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        self.header();
+        println!(
+            "total,{},,,,,,,success={} failed={} timeout={} panic={} invalid={},,{},{},,,,,,,,,,,",
+            summary.puzzles,
+            summary.success,
+            summary.failed,
+            summary.timeout,
+            summary.panic,
+            summary.invalid,
+            nanos(summary.elapsed),
+            nanos(summary.best),
+        );
+    }
+}
+
+/// `JUnit` XML, one test suite per year and one test case per solution.
+#[derive(Default)]
+pub struct JunitReporter {
+    records: Vec<RunRecord>,
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl JunitReporter {
+    fn render(&self, summary: &Summary) -> String {
+        let mut xml = String::new();
+
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="Advent of Code" tests="{}" failures="{}" errors="{}" time="{:.6}">"#,
+            summary.puzzles,
+            summary.failed,
+            summary.timeout + summary.panic + summary.invalid,
+            summary.elapsed.as_secs_f64()
+        );
+
+        for (year, records) in &self.records.iter().chunk_by(|r| r.year) {
+            let records: Vec<_> = records.collect();
+            let failures = records
+                .iter()
+                .filter(|r| r.status == Status::Failed)
+                .count();
+            let errors = records
+                .iter()
+                .filter(|r| matches!(r.status, Status::Timeout | Status::Panic | Status::Invalid))
+                .count();
+            let time: Duration = records.iter().map(|r| r.elapsed).sum();
+
+            let _ = writeln!(
+                xml,
+                r#"  <testsuite name="{year}" tests="{}" failures="{failures}" errors="{errors}" time="{:.6}">"#,
+                records.len(),
+                time.as_secs_f64()
+            );
+
+            for r in records {
+                let _ = writeln!(
+                    xml,
+                    r#"    <testcase classname="aoc.year{year}" name="{}" time="{:.6}" file="{}">"#,
+                    xml_escape(&r.name()),
+                    r.elapsed.as_secs_f64(),
+                    xml_escape(&r.source),
+                );
+                match r.status {
+                    Status::Failed => {
+                        let _ = writeln!(
+                            xml,
+                            r#"      <failure message="wrong answer">expected: {} / {}
+got: {} / {}</failure>"#,
+                            xml_escape(&r.answer1),
+                            xml_escape(&r.answer2),
+                            xml_escape(&r.part1),
+                            xml_escape(&r.part2),
+                        );
+                    }
+                    Status::Timeout | Status::Panic | Status::Invalid => {
+                        let _ = writeln!(
+                            xml,
+                            r#"      <error type="{}" message="{}"/>"#,
+                            r.status.as_str(),
+                            xml_escape(r.error.as_deref().unwrap_or_default()),
+                        );
+                    }
+                    Status::Success | Status::Unchecked => {
+                        let _ = writeln!(
+                            xml,
+                            "      <system-out>{}\n{}</system-out>",
+                            xml_escape(&r.part1),
+                            xml_escape(&r.part2),
+                        );
+                    }
+                }
+                let _ = writeln!(xml, "    </testcase>");
+            }
+
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+
+        let _ = writeln!(xml, "</testsuites>");
+        xml
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn record(&mut self, record: &RunRecord) {
+        self.records.push(record.clone());
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        print!("{}", self.render(summary));
+    }
+}
+
+/// The day × year timings tables of the README.
+#[derive(Default)]
+pub struct MarkdownReporter {
+    timings: BTreeMap<(u16, u8), Duration>,
+}
+
+impl Reporter for MarkdownReporter {
+    fn record(&mut self, record: &RunRecord) {
+        if record.is_readme_timing() {
+            self.timings.insert((record.year, record.day), record.best);
+        }
+    }
+
+    fn end(&mut self, _summary: &Summary) {
+        print!(
+            "{}",
+            markdown::timings_tables(&self.timings, markdown::YEARS_PER_TABLE)
+        );
+    }
+}
+
+/// Update the timings of the READMEs at the end of the run.
+pub struct ReadmeReporter {
+    root: PathBuf,
+    timings: BTreeMap<(u16, u8), Duration>,
+}
+
+impl ReadmeReporter {
+    /// The READMEs are searched in the `root` directory of the repository.
+    #[must_use]
+    pub const fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            timings: BTreeMap::new(),
+        }
+    }
+}
+
+impl Reporter for ReadmeReporter {
+    fn record(&mut self, record: &RunRecord) {
+        if record.is_readme_timing() {
+            self.timings.insert((record.year, record.day), record.best);
+        }
+    }
+
+    fn end(&mut self, _summary: &Summary) {
+        if let Err(err) = markdown::update_readmes(&self.root, &self.timings) {
+            eprintln!("failed to update the READMEs: {err}");
+        }
+    }
+}
+
+/// Forward the events to several reporters.
+pub struct Tee(pub Vec<Box<dyn Reporter>>);
+
+impl Reporter for Tee {
+    fn begin(&mut self) {
+        self.0.iter_mut().for_each(|r| r.begin());
+    }
+
+    fn start(&mut self, sol: &Solution, source: &str) {
+        self.0.iter_mut().for_each(|r| r.start(sol, source));
+    }
+
+    fn missing(&mut self, sol: &Solution, path: &str) {
+        self.0.iter_mut().for_each(|r| r.missing(sol, path));
+    }
+
+    fn record(&mut self, record: &RunRecord) {
+        self.0.iter_mut().for_each(|r| r.record(record));
+    }
+
+    fn end(&mut self, summary: &Summary) {
+        self.0.iter_mut().for_each(|r| r.end(summary));
+    }
+}
+
+/// Pass matrix of the solutions against the inputs of several users, printed at the end of the run.
+pub struct MatrixReporter {
+    /// Print the matrix to stdout, otherwise to stderr not to mix it with a structured output.
+    stdout: bool,
+    users: BTreeSet<String>,
+    rows: BTreeMap<(u16, u8, Option<String>), BTreeMap<String, Status>>,
+}
+
+impl MatrixReporter {
+    #[must_use]
+    pub const fn new(stdout: bool) -> Self {
+        Self {
+            stdout,
+            users: BTreeSet::new(),
+            rows: BTreeMap::new(),
+        }
+    }
+
+    const fn cell(status: Status) -> &'static str {
+        match status {
+            Status::Success => "ok",
+            Status::Failed => "FAIL",
+            Status::Unchecked => "?",
+            Status::Timeout => "TIME",
+            Status::Panic => "PANIC",
+            Status::Invalid => "BAD",
+        }
+    }
+
+    /// Render the matrix: one row per solution, one column per user,
+    /// and a last row with the number of passed puzzles of each user.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let widths: Vec<_> = self
+            .users
+            .iter()
+            .map(|user| user.chars().count().max(5))
+            .collect();
+
+        let mut text = String::new();
+
+        let mut line = format!("{:<20}", "puzzle");
+        for (user, width) in self.users.iter().zip(&widths) {
+            let _ = write!(line, " | {user:^width$}");
+        }
+        let _ = writeln!(text, "{}", line.trim_end());
+
+        for ((year, day, alt), statuses) in &self.rows {
+            let name = alt.as_ref().map_or_else(
+                || format!("{year} day {day}"),
+                |alt| format!("{year} day {day} ({alt})"),
+            );
+
+            let mut line = format!("{name:<20}");
+            for (user, width) in self.users.iter().zip(&widths) {
+                let cell = statuses.get(user).map_or("", |&s| Self::cell(s));
+                let _ = write!(line, " | {cell:^width$}");
+            }
+            let _ = writeln!(text, "{}", line.trim_end());
+        }
+
+        let mut line = format!("{:<20}", "passed");
+        for (user, width) in self.users.iter().zip(&widths) {
+            let (passed, total) = self
+                .rows
+                .values()
+                .filter_map(|statuses| statuses.get(user))
+                .fold((0, 0), |(passed, total), &status| {
+                    (passed + usize::from(status == Status::Success), total + 1)
+                });
+            let _ = write!(line, " | {:^width$}", format!("{passed}/{total}"));
+        }
+        let _ = writeln!(text, "{}", line.trim_end());
+
+        text
+    }
+}
+
+impl Reporter for MatrixReporter {
+    fn record(&mut self, record: &RunRecord) {
+        if let Some(user) = &record.user {
+            self.users.insert(user.clone());
+            self.rows
+                .entry((record.year, record.day, record.alt.clone()))
+                .or_default()
+                .insert(user.clone(), record.status);
+        }
+    }
+
+    fn end(&mut self, _summary: &Summary) {
+        if self.stdout {
+            println!();
+            print!("{}", self.render());
+        } else {
+            eprint!("{}", self.render());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(status: Status) -> RunRecord {
+        RunRecord {
+            year: 2015,
+            day: 1,
+            alt: None,
+            user: None,
+            part1: "74".to_string(),
+            part2: "1795".to_string(),
+            answer1: "74".to_string(),
+            answer2: if status == Status::Success {
+                "1795"
+            } else {
+                "42"
+            }
+            .to_string(),
+            status,
+            error: None,
+            warnings: Vec::new(),
+            elapsed: Duration::from_micros(5),
+            best: Duration::from_micros(4),
+            source: "input/2015/1.in".to_string(),
+            bench: None,
+            part: None,
+            phases: None,
+            bench_phases: None,
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("JUnit".parse(), Ok(Format::Junit));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_junit() {
+        let mut reporter = JunitReporter::default();
+        let mut summary = Summary::default();
+        let panicked =
+            record(Status::Success).aborted(Status::Panic, "<boom> at day1.rs:1:1".to_string());
+        for r in [record(Status::Success), record(Status::Failed), panicked] {
+            summary.add(&r);
+            reporter.record(&r);
+        }
+
+        let xml = reporter.render(&summary);
+        assert!(
+            xml.contains(r#"<testsuites name="Advent of Code" tests="3" failures="1" errors="1""#)
+        );
+        assert!(xml.contains(r#"<testsuite name="2015" tests="3" failures="1" errors="1""#));
+        assert_eq!(xml.matches("<failure ").count(), 1);
+        assert!(xml.contains(r#"<error type="panic" message="&lt;boom&gt; at day1.rs:1:1"/>"#));
+    }
+
+    #[test]
+    fn test_json() {
+        let r = record(Status::Success);
+        let json = r.to_json();
+        assert_eq!(json["status"], "success");
+        assert_eq!(json["elapsed_ns"], 5000);
+        assert_eq!(json["alt"], serde_json::Value::Null);
+        assert_eq!(json["phases"], serde_json::Value::Null);
+
+        let r = RunRecord {
+            part: Some(2),
+            phases: Some(Phases {
+                parse: Duration::from_micros(3),
+                part1: None,
+                part2: Some(Duration::from_micros(2)),
+            }),
+            ..record(Status::Success)
+        };
+        let json = r.to_json();
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["phases"]["parse_ns"], 3000);
+        assert_eq!(json["phases"]["part1_ns"], serde_json::Value::Null);
+        assert_eq!(json["phases"]["part2_ns"], 2000);
+        assert!(!r.is_readme_timing());
+    }
+
+    #[test]
+    fn test_matrix() {
+        let mut reporter = MatrixReporter::new(true);
+        for (user, status) in [("1234", Status::Success), ("alice", Status::Failed)] {
+            reporter.record(&RunRecord {
+                user: Some(user.to_string()),
+                ..record(status)
+            });
+        }
+        reporter.record(&RunRecord {
+            day: 2,
+            user: Some("1234".to_string()),
+            ..record(Status::Success)
+        });
+
+        assert_eq!(
+            reporter.render(),
+            "\
+puzzle               | 1234  | alice
+2015 day 1           |  ok   | FAIL
+2015 day 2           |  ok   |
+passed               |  2/2  |  0/1
+"
+        );
+    }
+}
@@ -1,25 +1,8 @@
 use std::{error::Error, path::PathBuf, time::Duration};
 
 use libsql::{Builder, Connection};
-use sha2::{Digest, Sha256};
 
-/// Trait for database backends that store puzzle execution timings.
-pub trait TimingsDb {
-    /// Updates the recorded execution time for a specific puzzle.
-    ///
-    /// It updates the record only if the new `elapsed` time is shorter than the one currently stored.
-    /// Returns the best execution time recorded so far.
-    ///
-    /// # Errors
-    /// Returns an error if the database operation fails or type conversion fails.
-    fn update(
-        &self,
-        year: u16,
-        day: u8,
-        data: &str,
-        elapsed: Duration,
-    ) -> Result<Duration, Box<dyn Error>>;
-}
+use crate::timings::{HistoryEntry, TimingsDb, digest, timestamp};
 
 /// SQLite-based implementation of execution timings database using Turso (libsql).
 pub struct RunDb {
@@ -28,7 +11,7 @@ pub struct RunDb {
 }
 
 impl RunDb {
-    /// Creates a new `RunDb` instance and ensures the `timings` table exists in `.timings.db`.
+    /// Creates a new `RunDb` instance and ensures the `timings` and `history` tables exist in `.timings.db`.
     ///
     /// # Errors
     /// Returns an error if the database file cannot be opened or the schema cannot be initialized.
@@ -54,6 +37,18 @@ impl RunDb {
                 elapsed_ns integer not null
             );
             create unique index if not exists idx_timings on timings (year,day,crc);
+            create table if not exists history (
+                year integer not null,
+                day integer not null,
+                alt text not null,
+                crc text not null,
+                elapsed_ns integer not null,
+                timestamp integer not null,
+                commit_hash text not null,
+                profile text not null,
+                host text not null
+            );
+            create index if not exists idx_history on history (year,day,alt,crc,commit_hash);
             ",
         ))?;
 
@@ -70,13 +65,7 @@ impl TimingsDb for RunDb {
         data: &str,
         elapsed: Duration,
     ) -> Result<Duration, Box<dyn Error>> {
-        let mut hasher = Sha256::new();
-        hasher.update(data.trim_ascii());
-        let digest = hasher
-            .finalize()
-            .iter()
-            .map(|b| format!("{:02x}", b))
-            .collect::<String>();
+        let digest = digest(data);
 
         let elapsed_nanos = i64::try_from(elapsed.as_nanos())?;
 
@@ -120,4 +109,85 @@ impl TimingsDb for RunDb {
 
         Ok(Duration::from_nanos(u64::try_from(best_nanos)?))
     }
+
+    /// # Errors
+    fn best(&self, year: u16, day: u8, data: &str) -> Result<Option<Duration>, Box<dyn Error>> {
+        let best_nanos = self.rt.block_on(async {
+            let mut rows = self
+                .conn
+                .query(
+                    "select elapsed_ns from timings where year=?1 and day=?2 and crc=?3",
+                    (i64::from(year), i64::from(day), digest(data)),
+                )
+                .await?;
+
+            match rows.next().await? {
+                Some(row) => Ok::<Option<i64>, Box<dyn Error>>(Some(row.get(0)?)),
+                None => Ok(None),
+            }
+        })?;
+
+        best_nanos
+            .map(|nanos| Ok(Duration::from_nanos(u64::try_from(nanos)?)))
+            .transpose()
+    }
+
+    /// # Errors
+    fn add_history(&self, run: &HistoryEntry) -> Result<(), Box<dyn Error>> {
+        let timestamp = i64::try_from(timestamp()?)?;
+
+        self.rt.block_on(self.conn.execute(
+            "insert into history (year,day,alt,crc,elapsed_ns,timestamp,commit_hash,profile,host)
+                values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            (
+                i64::from(run.year),
+                i64::from(run.day),
+                run.alt.unwrap_or_default(),
+                digest(run.data),
+                i64::try_from(run.elapsed.as_nanos())?,
+                timestamp,
+                run.build.commit.as_str(),
+                run.build.profile.as_str(),
+                run.build.host.as_str(),
+            ),
+        ))?;
+
+        Ok(())
+    }
+
+    /// # Errors
+    fn history_best(
+        &self,
+        year: u16,
+        day: u8,
+        alt: Option<&str>,
+        data: &str,
+        commit: &str,
+    ) -> Result<Option<Duration>, Box<dyn Error>> {
+        let best_nanos = self.rt.block_on(async {
+            let mut rows = self
+                .conn
+                .query(
+                    "select min(elapsed_ns) from history
+                        where year=?1 and day=?2 and alt=?3 and crc=?4 and commit_hash=?5",
+                    (
+                        i64::from(year),
+                        i64::from(day),
+                        alt.unwrap_or_default(),
+                        digest(data),
+                        commit,
+                    ),
+                )
+                .await?;
+
+            match rows.next().await? {
+                Some(row) => Ok::<Option<i64>, Box<dyn Error>>(row.get(0)?),
+                None => Ok(None),
+            }
+        })?;
+
+        best_nanos
+            .map(|nanos| Ok(Duration::from_nanos(u64::try_from(nanos)?)))
+            .transpose()
+    }
 }
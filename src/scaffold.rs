@@ -0,0 +1,173 @@
+//! Scaffolding of a new day: its solution from a template, its first sample and its row in the year README.
+//!
+//! The registry is generated by `build.rs` from the source tree: the new day is registered by the next build.
+
+use crate::markdown;
+use std::path::{Path, PathBuf};
+
+/// The solution of a new day, with the `Puzzle` entry points and a test of the first sample.
+#[must_use]
+pub fn template(year: u16, day: u8, title: &str) -> String {
+    format!(
+        r#"//! [Day {day}: {title}](https://adventofcode.com/{year}/day/{day})
+
+pub struct Puzzle {{
+    data: String,
+}}
+
+impl Puzzle {{
+    /// Initialize from the puzzle input.
+    #[must_use]
+    pub fn new(data: &str) -> Self {{
+        Self {{
+            data: data.to_string(),
+        }}
+    }}
+
+    /// Solve part one.
+    #[must_use]
+    pub fn part1(&self) -> usize {{
+        self.data.lines().count()
+    }}
+
+    /// Solve part two.
+    #[must_use]
+    pub fn part2(&self) -> usize {{
+        self.data.split_whitespace().count()
+    }}
+}}
+
+/// # Panics
+#[must_use]
+pub fn solve(data: &str) -> (usize, usize) {{
+    let puzzle = Puzzle::new(data);
+    (puzzle.part1(), puzzle.part2())
+}}
+
+pub fn main() {{
+    let args = aoc::parse_args();
+    args.run(solve);
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    const SAMPLE_1: &str = include_str!("sample_1.txt");
+
+    #[test]
+    fn part1() {{
+        let puzzle = Puzzle::new(SAMPLE_1);
+        assert_eq!(puzzle.part1(), 0);
+    }}
+
+    #[test]
+    fn part2() {{
+        let puzzle = Puzzle::new(SAMPLE_1);
+        assert_eq!(puzzle.part2(), 0);
+    }}
+}}
+"#
+    )
+}
+
+/// The samples manifest of a new day, whose sample has no expected answers yet.
+const SAMPLES_TOML: &str = "\
+# expected answers of the samples, for `aor --samples`
+# [sample_1]
+# part1 = 0
+# part2 = 0
+";
+
+/// Create the directory of a new day with its solution, an empty first sample and its samples manifest,
+/// and add the day to the README of its year, creating it for a new year.
+///
+/// Returns the created or updated files.
+///
+/// # Errors
+///
+/// Returns an error if the year or the day is not valid, if the day already has a Rust solution,
+/// or if a file cannot be written.
+pub fn create(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if year < 2015 || !(1..=25).contains(&day) {
+        return Err(format!("invalid puzzle: {year} day {day}"));
+    }
+
+    let year_dir = root.join("src").join(format!("year{year}"));
+    let day_dir = year_dir.join(format!("day{day}"));
+    let source = day_dir.join(format!("day{day}.rs"));
+
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+
+    let write = |path: &Path, content: &str| {
+        std::fs::write(path, content).map_err(|err| format!("{}: {err}", path.display()))
+    };
+
+    std::fs::create_dir_all(&day_dir).map_err(|err| format!("{}: {err}", day_dir.display()))?;
+
+    let mut created = vec![source.clone()];
+    write(&source, &template(year, day, title))?;
+
+    // the other files may come with the solutions in other languages
+    for (name, content) in [("sample_1.txt", ""), ("samples.toml", SAMPLES_TOML)] {
+        let path = day_dir.join(name);
+        if !path.exists() {
+            write(&path, content)?;
+            created.push(path);
+        }
+    }
+
+    let readme_path = year_dir.join("README.md");
+    let readme =
+        std::fs::read_to_string(&readme_path).unwrap_or_else(|_| markdown::year_readme(year));
+    let updated = markdown::year_readme_add_day(&readme, year, day, title);
+    if updated != readme || !readme_path.exists() {
+        write(&readme_path, &updated)?;
+        created.push(readme_path);
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aor-scaffold-{}", std::process::id()));
+
+        let created = create(&root, 2026, 3, "Lobby").unwrap();
+        let names: Vec<_> = created
+            .iter()
+            .map(|path| path.strip_prefix(&root).unwrap().display().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "src/year2026/day3/day3.rs",
+                "src/year2026/day3/sample_1.txt",
+                "src/year2026/day3/samples.toml",
+                "src/year2026/README.md"
+            ]
+        );
+
+        let source = std::fs::read_to_string(&created[0]).unwrap();
+        assert!(source.starts_with("//! [Day 3: Lobby](https://adventofcode.com/2026/day/3)\n"));
+        assert!(source.contains("include_str!(\"sample_1.txt\")"));
+
+        let readme = std::fs::read_to_string(&created[3]).unwrap();
+        assert!(readme.contains("\n[Day 3: Lobby](https://adventofcode.com/2026/day/3) |"));
+
+        // existing work is kept
+        std::fs::write(&created[1], "sample").unwrap();
+        assert!(create(&root, 2026, 3, "Lobby").is_err());
+        assert_eq!(std::fs::read_to_string(&created[1]).unwrap(), "sample");
+
+        assert!(create(&root, 2026, 26, "Lobby").is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
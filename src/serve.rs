@@ -0,0 +1,161 @@
+//! Newline-delimited JSON protocol of `aor --serve-stdio`, to run many solutions from one process.
+//!
+//! Each request is a JSON object on a line, with the puzzle and either the path of the input or the input itself:
+//!
+//! ```json
+//! {"id": 1, "year": 2015, "day": 1, "input": "input/2015/1.in"}
+//! {"id": 2, "year": 2016, "day": 5, "alt": "orig", "data": "abc", "part": 1, "answer1": "18f47a30"}
+//! ```
+//!
+//! Without `input` nor `data`, the default input of the puzzle is used. Each response is the record of the run
+//! on a line, like with `--format jsonl`, with the `id` of its request, or `{"id": 1, "error": "..."}`.
+
+use crate::report::RunRecord;
+use serde_json::Value;
+
+/// Where the input of a request comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    /// The path of the input file.
+    Path(String),
+    /// The input itself.
+    Data(String),
+    /// The default input of the puzzle.
+    Default,
+}
+
+/// A request to run a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    /// Any JSON value, sent back with the response.
+    pub id: Value,
+    pub year: u16,
+    pub day: u8,
+    pub alt: Option<String>,
+    pub input: Input,
+    /// The only part to solve.
+    pub part: Option<u8>,
+    /// The expected answers, if any.
+    pub answer1: Option<String>,
+    pub answer2: Option<String>,
+}
+
+impl Request {
+    /// Parse a request line.
+    ///
+    /// # Errors
+    /// Returns the id of the request, if any, and the reason why it is not valid.
+    pub fn parse(line: &str) -> Result<Self, (Value, String)> {
+        let request: Value =
+            serde_json::from_str(line).map_err(|err| (Value::Null, err.to_string()))?;
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let fail = |message: String| (id.clone(), message);
+
+        if !request.is_object() {
+            return Err(fail("expected an object".to_string()));
+        }
+
+        let number = |name: &str| -> Result<Option<u64>, (Value, String)> {
+            match &request[name] {
+                Value::Null => Ok(None),
+                value => value
+                    .as_u64()
+                    .map(Some)
+                    .ok_or_else(|| fail(format!("{name} must be a number"))),
+            }
+        };
+        let text = |name: &str| -> Result<Option<String>, (Value, String)> {
+            match &request[name] {
+                Value::Null => Ok(None),
+                Value::String(s) => Ok(Some(s.clone())),
+                Value::Number(n) if name.starts_with("answer") => Ok(Some(n.to_string())),
+                _ => Err(fail(format!("{name} must be a string"))),
+            }
+        };
+
+        let year = number("year")?
+            .and_then(|year| u16::try_from(year).ok())
+            .ok_or_else(|| fail("missing or invalid year".to_string()))?;
+        let day = number("day")?
+            .and_then(|day| u8::try_from(day).ok())
+            .ok_or_else(|| fail("missing or invalid day".to_string()))?;
+
+        let part = match number("part")? {
+            None => None,
+            Some(part @ (1 | 2)) => u8::try_from(part).ok(),
+            Some(_) => return Err(fail("part must be 1 or 2".to_string())),
+        };
+
+        let input = match (text("input")?, text("data")?) {
+            (Some(_), Some(_)) => return Err(fail("input and data are exclusive".to_string())),
+            (Some(path), None) => Input::Path(path),
+            (None, Some(data)) => Input::Data(data),
+            (None, None) => Input::Default,
+        };
+
+        Ok(Self {
+            year,
+            day,
+            alt: text("alt")?,
+            input,
+            part,
+            answer1: text("answer1")?,
+            answer2: text("answer2")?,
+            id,
+        })
+    }
+}
+
+/// The response line to a request: the JSON record of the run with the id of the request.
+#[must_use]
+pub fn response(id: &Value, record: &RunRecord) -> String {
+    let mut json = record.to_json();
+    json["id"] = id.clone();
+    json.to_string()
+}
+
+/// The response line to a request that cannot be run.
+#[must_use]
+pub fn error(id: &Value, message: &str) -> String {
+    serde_json::json!({ "id": id, "error": message }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let request = Request::parse(r#"{"id": "a", "year": 2016, "day": 5, "alt": "orig", "data": "abc", "part": 1, "answer1": 42}"#)
+            .unwrap();
+        assert_eq!(request.id, "a");
+        assert_eq!((request.year, request.day), (2016, 5));
+        assert_eq!(request.alt.as_deref(), Some("orig"));
+        assert_eq!(request.input, Input::Data("abc".to_string()));
+        assert_eq!(request.part, Some(1));
+        assert_eq!(request.answer1.as_deref(), Some("42"));
+
+        let request = Request::parse(r#"{"year": 2015, "day": 1}"#).unwrap();
+        assert_eq!(request.id, Value::Null);
+        assert_eq!(request.input, Input::Default);
+
+        assert_eq!(
+            Request::parse(r#"{"id": 7, "year": 2015}"#).unwrap_err(),
+            (Value::from(7), "missing or invalid day".to_string())
+        );
+        assert_eq!(
+            Request::parse(r#"{"id": 8, "year": 2015, "day": 1, "part": 3}"#).unwrap_err(),
+            (Value::from(8), "part must be 1 or 2".to_string())
+        );
+        assert!(Request::parse("[2015, 1]").is_err());
+        assert!(Request::parse("{").is_err());
+    }
+
+    #[test]
+    fn test_error() {
+        assert_eq!(
+            error(&Value::from(3), "unknown solution"),
+            r#"{"error":"unknown solution","id":3}"#
+        );
+    }
+}
@@ -0,0 +1,559 @@
+//! Storage of the puzzle execution timings: the backend trait and the implementations
+//! that do not need libsql, in memory and in a JSON file.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    error::Error,
+    fmt::Write,
+    io::Write as _,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use sha2::{Digest, Sha256};
+
+/// Trait for database backends that store puzzle execution timings.
+pub trait TimingsDb {
+    /// Updates the recorded execution time for a specific puzzle.
+    ///
+    /// It updates the record only if the new `elapsed` time is shorter than the one currently stored.
+    /// Returns the best execution time recorded so far.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails or type conversion fails.
+    fn update(
+        &self,
+        year: u16,
+        day: u8,
+        data: &str,
+        elapsed: Duration,
+    ) -> Result<Duration, Box<dyn Error>>;
+
+    /// Returns the best execution time recorded for a specific puzzle input, if any.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails or type conversion fails.
+    fn best(&self, year: u16, day: u8, data: &str) -> Result<Option<Duration>, Box<dyn Error>>;
+
+    /// Appends a run to the history.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails or type conversion fails.
+    fn add_history(&self, run: &HistoryEntry) -> Result<(), Box<dyn Error>>;
+
+    /// Returns the best execution time recorded in the history for a puzzle input at a given commit.
+    ///
+    /// # Errors
+    /// Returns an error if the database operation fails or type conversion fails.
+    fn history_best(
+        &self,
+        year: u16,
+        day: u8,
+        alt: Option<&str>,
+        data: &str,
+        commit: &str,
+    ) -> Result<Option<Duration>, Box<dyn Error>>;
+
+    /// Returns true if the history outlives the process, so that it can be compared with.
+    fn has_history(&self) -> bool {
+        true
+    }
+}
+
+/// A run of a solution, as stored in the history.
+pub struct HistoryEntry<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub alt: Option<&'a str>,
+    pub data: &'a str,
+    pub elapsed: Duration,
+    pub build: &'a BuildInfo,
+}
+
+/// Where and with what a run happened.
+#[derive(Clone, Debug)]
+pub struct BuildInfo {
+    /// Git commit of the sources the binary was built from.
+    pub commit: String,
+    /// Cargo profile, `debug` or `release`.
+    pub profile: String,
+    /// Name of the machine.
+    pub host: String,
+    /// Cargo features the binary was built with.
+    pub features: Vec<String>,
+    /// Version of the compiler.
+    pub rustc: String,
+    /// Model of the processor.
+    pub cpu: String,
+}
+
+impl BuildInfo {
+    /// Returns the build info of the running binary.
+    #[must_use]
+    pub fn current() -> Self {
+        let host = std::env::var("HOSTNAME")
+            .ok()
+            .or_else(|| {
+                std::process::Command::new("hostname")
+                    .output()
+                    .ok()
+                    .and_then(|output| String::from_utf8(output.stdout).ok())
+            })
+            .map_or_else(|| "unknown".to_string(), |s| s.trim().to_string());
+
+        Self {
+            commit: env!("AOR_GIT_COMMIT").to_string(),
+            profile: env!("AOR_PROFILE").to_string(),
+            host,
+            features: enabled_features().into_iter().map(str::to_string).collect(),
+            rustc: env!("AOR_RUSTC_VERSION").to_string(),
+            cpu: cpu_model(),
+        }
+    }
+}
+
+/// Returns the cargo features of the running binary.
+#[must_use]
+pub fn enabled_features() -> Vec<&'static str> {
+    [
+        ("ascii", cfg!(feature = "ascii")),
+        ("anim", cfg!(feature = "anim")),
+        ("timingsdb", cfg!(feature = "timingsdb")),
+    ]
+    .into_iter()
+    .filter_map(|(feature, enabled)| enabled.then_some(feature))
+    .collect()
+}
+
+/// Returns the model of the processor, from `/proc/cpuinfo` or `sysctl`.
+fn cpu_model() -> String {
+    std::fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|cpuinfo| {
+            cpuinfo.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                (key.trim() == "model name").then(|| value.trim().to_string())
+            })
+        })
+        .or_else(|| {
+            std::process::Command::new("sysctl")
+                .args(["-n", "machdep.cpu.brand_string"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|s| s.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Resolves a git revision (branch, tag, abbreviated hash...) to a full commit hash.
+#[must_use]
+pub fn resolve_commit(rev: &str) -> Option<String> {
+    std::process::Command::new("git")
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+}
+
+/// Returns the SHA-256 of the trimmed puzzle input, as an hex string.
+pub(crate) fn digest(data: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data.trim_ascii());
+    hasher.finalize().iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+/// Returns the current time as seconds since the Unix epoch.
+pub(crate) fn timestamp() -> Result<u64, Box<dyn Error>> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+fn nanos(d: Duration) -> Result<u64, Box<dyn Error>> {
+    Ok(u64::try_from(d.as_nanos())?)
+}
+
+/// A stored run of the history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct HistoryRow {
+    year: u16,
+    day: u8,
+    alt: String,
+    crc: String,
+    elapsed: Duration,
+    timestamp: u64,
+    commit: String,
+    profile: String,
+    host: String,
+}
+
+/// Volatile implementation of the execution timings database, mainly for tests.
+#[derive(Default)]
+pub struct MemoryDb {
+    timings: RefCell<HashMap<(u16, u8, String), Duration>>,
+    history: RefCell<Vec<HistoryRow>>,
+}
+
+impl MemoryDb {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TimingsDb for MemoryDb {
+    fn update(
+        &self,
+        year: u16,
+        day: u8,
+        data: &str,
+        elapsed: Duration,
+    ) -> Result<Duration, Box<dyn Error>> {
+        let mut timings = self.timings.borrow_mut();
+        let best = timings.entry((year, day, digest(data))).or_insert(elapsed);
+        *best = (*best).min(elapsed);
+        Ok(*best)
+    }
+
+    fn best(&self, year: u16, day: u8, data: &str) -> Result<Option<Duration>, Box<dyn Error>> {
+        Ok(self
+            .timings
+            .borrow()
+            .get(&(year, day, digest(data)))
+            .copied())
+    }
+
+    fn add_history(&self, run: &HistoryEntry) -> Result<(), Box<dyn Error>> {
+        self.history.borrow_mut().push(HistoryRow {
+            year: run.year,
+            day: run.day,
+            alt: run.alt.unwrap_or_default().to_string(),
+            crc: digest(run.data),
+            elapsed: run.elapsed,
+            timestamp: timestamp()?,
+            commit: run.build.commit.clone(),
+            profile: run.build.profile.clone(),
+            host: run.build.host.clone(),
+        });
+        Ok(())
+    }
+
+    fn history_best(
+        &self,
+        year: u16,
+        day: u8,
+        alt: Option<&str>,
+        data: &str,
+        commit: &str,
+    ) -> Result<Option<Duration>, Box<dyn Error>> {
+        let alt = alt.unwrap_or_default();
+        let crc = digest(data);
+
+        Ok(self
+            .history
+            .borrow()
+            .iter()
+            .filter(|row| {
+                row.year == year
+                    && row.day == day
+                    && row.alt == alt
+                    && row.crc == crc
+                    && row.commit == commit
+            })
+            .map(|row| row.elapsed)
+            .min())
+    }
+
+    fn has_history(&self) -> bool {
+        false
+    }
+}
+
+/// JSON file implementation of the execution timings database.
+///
+/// The best timings are loaded when opened and the file is rewritten only when one improves.
+/// The history is appended to a JSON Lines file alongside, like `.timings.history.jsonl`.
+pub struct FileDb {
+    path: PathBuf,
+    history_path: PathBuf,
+    mem: MemoryDb,
+}
+
+impl FileDb {
+    /// Opens the database stored in `path`, or creates an empty one if the file does not exist.
+    ///
+    /// The history of a file written by an older version is moved to the JSON Lines file.
+    ///
+    /// # Errors
+    /// Returns an error if the files cannot be read or are not a valid timings database.
+    pub fn open(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let db = Self {
+            path: path.to_path_buf(),
+            history_path: path.with_extension("history.jsonl"),
+            mem: MemoryDb::new(),
+        };
+
+        if db.history_path.is_file() {
+            for line in std::fs::read_to_string(&db.history_path)?.lines() {
+                if !line.trim_ascii().is_empty() {
+                    let row = history_row(&serde_json::from_str(line)?)?;
+                    db.mem.history.borrow_mut().push(row);
+                }
+            }
+        }
+
+        if path.is_file() {
+            let doc: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+            for v in doc["timings"].as_array().into_iter().flatten() {
+                db.mem.timings.borrow_mut().insert(
+                    (
+                        u16::try_from(field(v, "year")?)?,
+                        u8::try_from(field(v, "day")?)?,
+                        text(v, "crc"),
+                    ),
+                    Duration::from_nanos(field(v, "elapsed_ns")?),
+                );
+            }
+
+            if let Some(history) = doc["history"].as_array() {
+                for v in history {
+                    let row = history_row(v)?;
+                    db.append_history(&row)?;
+                    db.mem.history.borrow_mut().push(row);
+                }
+                db.save()?;
+            }
+        }
+
+        Ok(db)
+    }
+
+    /// Rewrite the best timings.
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut timings = Vec::new();
+        for ((year, day, crc), elapsed) in self.mem.timings.borrow().iter() {
+            timings.push(serde_json::json!({
+                "year": year,
+                "day": day,
+                "crc": crc,
+                "elapsed_ns": nanos(*elapsed)?,
+            }));
+        }
+        // stable output, friendlier to diff
+        timings.sort_by_key(|v| (v["year"].as_u64(), v["day"].as_u64(), v["crc"].to_string()));
+
+        let doc = serde_json::json!({ "timings": timings });
+        std::fs::write(&self.path, format!("{doc:#}\n"))?;
+
+        Ok(())
+    }
+
+    /// Append a run to the history file.
+    fn append_history(&self, row: &HistoryRow) -> Result<(), Box<dyn Error>> {
+        let line = serde_json::json!({
+            "year": row.year,
+            "day": row.day,
+            "alt": row.alt,
+            "crc": row.crc,
+            "elapsed_ns": nanos(row.elapsed)?,
+            "timestamp": row.timestamp,
+            "commit": row.commit,
+            "profile": row.profile,
+            "host": row.host,
+        });
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.history_path)?;
+        writeln!(file, "{line}")?;
+
+        Ok(())
+    }
+}
+
+fn field(v: &serde_json::Value, name: &str) -> Result<u64, Box<dyn Error>> {
+    v[name]
+        .as_u64()
+        .ok_or_else(|| format!("missing field {name}").into())
+}
+
+fn text(v: &serde_json::Value, name: &str) -> String {
+    v[name].as_str().unwrap_or_default().to_string()
+}
+
+fn history_row(v: &serde_json::Value) -> Result<HistoryRow, Box<dyn Error>> {
+    Ok(HistoryRow {
+        year: u16::try_from(field(v, "year")?)?,
+        day: u8::try_from(field(v, "day")?)?,
+        alt: text(v, "alt"),
+        crc: text(v, "crc"),
+        elapsed: Duration::from_nanos(field(v, "elapsed_ns")?),
+        timestamp: field(v, "timestamp")?,
+        commit: text(v, "commit"),
+        profile: text(v, "profile"),
+        host: text(v, "host"),
+    })
+}
+
+impl TimingsDb for FileDb {
+    fn update(
+        &self,
+        year: u16,
+        day: u8,
+        data: &str,
+        elapsed: Duration,
+    ) -> Result<Duration, Box<dyn Error>> {
+        let previous = self.mem.best(year, day, data)?;
+        let best = self.mem.update(year, day, data, elapsed)?;
+        if previous != Some(best) {
+            self.save()?;
+        }
+        Ok(best)
+    }
+
+    fn best(&self, year: u16, day: u8, data: &str) -> Result<Option<Duration>, Box<dyn Error>> {
+        self.mem.best(year, day, data)
+    }
+
+    fn add_history(&self, run: &HistoryEntry) -> Result<(), Box<dyn Error>> {
+        self.mem.add_history(run)?;
+        let row = self.mem.history.borrow().last().cloned();
+        row.map_or(Ok(()), |row| self.append_history(&row))
+    }
+
+    fn history_best(
+        &self,
+        year: u16,
+        day: u8,
+        alt: Option<&str>,
+        data: &str,
+        commit: &str,
+    ) -> Result<Option<Duration>, Box<dyn Error>> {
+        self.mem.history_best(year, day, alt, data, commit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(commit: &str) -> BuildInfo {
+        BuildInfo {
+            commit: commit.to_string(),
+            profile: "release".to_string(),
+            host: "test".to_string(),
+            features: Vec::new(),
+            rustc: "rustc".to_string(),
+            cpu: "cpu".to_string(),
+        }
+    }
+
+    fn fill(db: &dyn TimingsDb) {
+        let ms = Duration::from_millis;
+
+        assert_eq!(db.update(2015, 1, "input", ms(5)).unwrap(), ms(5));
+        assert_eq!(db.update(2015, 1, "input", ms(7)).unwrap(), ms(5));
+        assert_eq!(db.update(2015, 1, "input\n", ms(3)).unwrap(), ms(3));
+        assert_eq!(db.update(2015, 1, "other", ms(9)).unwrap(), ms(9));
+
+        for (commit, elapsed) in [("aaa", 10), ("aaa", 8), ("bbb", 12)] {
+            let build = build(commit);
+            let entry = HistoryEntry {
+                year: 2015,
+                day: 1,
+                alt: None,
+                data: "input",
+                elapsed: ms(elapsed),
+                build: &build,
+            };
+            db.add_history(&entry).unwrap();
+        }
+    }
+
+    fn check(db: &dyn TimingsDb) {
+        let ms = Duration::from_millis;
+
+        assert_eq!(db.best(2015, 1, "input").unwrap(), Some(ms(3)));
+        assert_eq!(db.best(2015, 2, "input").unwrap(), None);
+        assert_eq!(db.update(2015, 1, "input", ms(4)).unwrap(), ms(3));
+        assert_eq!(
+            db.history_best(2015, 1, None, "input", "aaa").unwrap(),
+            Some(ms(8))
+        );
+        assert_eq!(
+            db.history_best(2015, 1, None, "input", "bbb").unwrap(),
+            Some(ms(12))
+        );
+        assert_eq!(
+            db.history_best(2015, 1, None, "input", "ccc").unwrap(),
+            None
+        );
+        assert_eq!(
+            db.history_best(2015, 1, Some("alt"), "input", "aaa")
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_memory_db() {
+        let db = MemoryDb::new();
+        fill(&db);
+        check(&db);
+    }
+
+    #[test]
+    fn test_file_db() {
+        let path = std::env::temp_dir().join(format!("aor-timings-{}.json", std::process::id()));
+        let history = path.with_extension("history.jsonl");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&history);
+
+        fill(&FileDb::open(&path).unwrap());
+        check(&FileDb::open(&path).unwrap());
+
+        // one line per run, and no history in the timings file
+        assert_eq!(
+            std::fs::read_to_string(&history).unwrap().lines().count(),
+            3
+        );
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("history"));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&history).unwrap();
+    }
+
+    #[test]
+    fn test_file_db_legacy() {
+        let path = std::env::temp_dir().join(format!("aor-legacy-{}.json", std::process::id()));
+        let history = path.with_extension("history.jsonl");
+        let _ = std::fs::remove_file(&history);
+
+        let row = r#"{"year":2015,"day":1,"alt":"","crc":"x","elapsed_ns":5,"timestamp":1,"commit":"aaa","profile":"release","host":"test"}"#;
+        std::fs::write(&path, format!(r#"{{"timings":[],"history":[{row}]}}"#)).unwrap();
+
+        let db = FileDb::open(&path).unwrap();
+        assert_eq!(db.mem.history.borrow().len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(&history).unwrap().lines().count(),
+            1
+        );
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("history"));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&history).unwrap();
+    }
+}
@@ -0,0 +1,174 @@
+//! Sanity checks of the puzzle inputs, before solving.
+//!
+//! The generic checks look for an empty input, CRLF line endings, a byte order mark and trailing whitespace.
+//! The inputs are also compared with the ones of the other days, to flag a wrong year or day,
+//! and checked by the `validate` hook of the solution, if any.
+
+use crate::Solution;
+use crate::timings::digest;
+use aoc::InputError;
+use std::collections::HashMap;
+
+/// How bad an issue is: the solutions are not run with invalid inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A problem found in a puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    const fn warning(message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message,
+        }
+    }
+
+    const fn error(message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+        }
+    }
+}
+
+/// Run the generic checks on a raw input.
+#[must_use]
+pub fn check(data: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if data.trim_ascii().is_empty() {
+        issues.push(Issue::error(
+            InputError::new(1, 1, "empty input").to_string(),
+        ));
+        return issues;
+    }
+
+    if data.starts_with('\u{feff}') {
+        issues.push(Issue::warning(
+            InputError::new(1, 1, "byte order mark").to_string(),
+        ));
+    }
+
+    if let Some(offset) = data.find("\r\n") {
+        issues.push(Issue::warning(
+            InputError::at(data, offset, "CRLF line endings").to_string(),
+        ));
+    }
+
+    let mut trailing = data
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .enumerate()
+        .filter(|(_, line)| line.ends_with([' ', '\t']));
+
+    if let Some((first, line)) = trailing.next() {
+        let column = line.trim_end_matches([' ', '\t']).chars().count() + 1;
+        let others = trailing.count();
+        let message = if others == 0 {
+            "trailing whitespace".to_string()
+        } else {
+            format!("trailing whitespace, and on {others} other line(s)")
+        };
+        issues.push(Issue::warning(
+            InputError::new(first + 1, column, &message).to_string(),
+        ));
+    }
+
+    issues
+}
+
+/// Check the inputs of the solutions, remembering them to detect the ones used for different days.
+#[derive(Default)]
+pub struct Validator {
+    seen: HashMap<String, (u16, u8)>,
+}
+
+impl Validator {
+    /// Check a raw input with the generic checks, then against the other days and with the
+    /// `validate` hook of the solution, once normalized.
+    pub fn check(&mut self, sol: &Solution, data: &str) -> Vec<Issue> {
+        let mut issues = check(data);
+        if issues.iter().any(|issue| issue.severity == Severity::Error) {
+            return issues;
+        }
+
+        // only a warning: some puzzles share their example input
+        let (year, day) = *self.seen.entry(digest(data)).or_insert((sol.year, sol.day));
+        if (year, day) != (sol.year, sol.day) {
+            issues.push(Issue::warning(format!(
+                "same input as {year} day {day}, wrong year or day?"
+            )));
+        }
+
+        if let Some(validate) = sol.validate
+            && let Err(err) = validate(&aoc::normalize_input(data.to_string()))
+        {
+            issues.push(Issue::error(err.to_string()));
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("1x2x3\n4x5x6\n"), vec![]);
+
+        assert_eq!(
+            check(" \n"),
+            vec![Issue::error("line 1, column 1: empty input".to_string())]
+        );
+
+        assert_eq!(
+            check("\u{feff}1x2x3\r\n4x5x6 \r\n7x8x9\t\r\n"),
+            vec![
+                Issue::warning("line 1, column 1: byte order mark".to_string()),
+                Issue::warning("line 1, column 7: CRLF line endings".to_string()),
+                Issue::warning(
+                    "line 2, column 6: trailing whitespace, and on 1 other line(s)".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validator() {
+        let sol = |year, day| Solution {
+            year,
+            day,
+            alt: None,
+            title: "",
+            tags: &[],
+            slow: false,
+            answers: ("", ""),
+            languages: &[],
+            solve: |_| (String::new(), String::new()),
+            parse: None,
+            validate: None,
+            samples: &[],
+            main: || (),
+        };
+
+        let mut validator = Validator::default();
+        assert_eq!(validator.check(&sol(2016, 14), "abc\n"), vec![]);
+        assert_eq!(validator.check(&sol(2016, 14), "abc\n"), vec![]);
+        assert_eq!(
+            validator.check(&sol(2016, 5), "abc\n"),
+            vec![Issue::warning(
+                "same input as 2016 day 14, wrong year or day?".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Watch mode: poll the sources, the input and the samples of a solution, and run it again on change.
+//!
+//! A change of the input is handled by the running binary. A change of the sources (or of the samples,
+//! that are included in the binary) requires to rebuild it with cargo, then to restart it:
+//! the answers of the last run are passed to the new process to be compared with.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+/// The environment variable with the answers of the last run, across the restarts.
+const PREVIOUS_VAR: &str = "AOR_WATCH_PREVIOUS";
+
+/// The modification times of the watched files.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn scan(path: &Path, snapshot: &mut Snapshot) {
+    if path.is_dir() {
+        if let Ok(entries) = std::fs::read_dir(path) {
+            for entry in entries.filter_map(Result::ok) {
+                scan(&entry.path(), snapshot);
+            }
+        }
+    } else if let Ok(modified) = path.metadata().and_then(|m| m.modified()) {
+        snapshot.insert(path.to_path_buf(), modified);
+    }
+}
+
+/// Poll files and directories for changes.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: Snapshot,
+    interval: Duration,
+}
+
+impl Watcher {
+    /// Watch the files, and the files of the directories, that may not exist yet.
+    #[must_use]
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let mut watcher = Self {
+            paths,
+            snapshot: Snapshot::new(),
+            interval,
+        };
+        watcher.snapshot = watcher.scan();
+        watcher
+    }
+
+    fn scan(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        for path in &self.paths {
+            scan(path, &mut snapshot);
+        }
+        snapshot
+    }
+
+    /// Return the files created, modified or deleted since the last call.
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let snapshot = self.scan();
+
+        let changed = snapshot
+            .iter()
+            .filter(|(path, modified)| self.snapshot.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.snapshot
+                    .keys()
+                    .filter(|path| !snapshot.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+
+        self.snapshot = snapshot;
+        changed
+    }
+
+    /// Wait for changes, and let the editors finish writing the files.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            std::thread::sleep(self.interval);
+
+            let mut changed = self.changes();
+            if !changed.is_empty() {
+                std::thread::sleep(self.interval);
+                changed.extend(self.changes());
+                changed.sort();
+                changed.dedup();
+                return changed;
+            }
+        }
+    }
+}
+
+/// The answers and the elapsed time of a run, to compare with the next one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+    pub elapsed: Duration,
+}
+
+impl Answers {
+    /// The answers of the last run of the previous process, if restarted.
+    #[must_use]
+    pub fn previous() -> Option<Self> {
+        let value: serde_json::Value =
+            serde_json::from_str(&std::env::var(PREVIOUS_VAR).ok()?).ok()?;
+
+        Some(Self {
+            part1: value["part1"].as_str()?.to_string(),
+            part2: value["part2"].as_str()?.to_string(),
+            elapsed: Duration::from_nanos(value["elapsed_ns"].as_u64()?),
+        })
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::json!({
+            "part1": self.part1,
+            "part2": self.part2,
+            "elapsed_ns": u64::try_from(self.elapsed.as_nanos()).unwrap_or(u64::MAX),
+        })
+        .to_string()
+    }
+
+    /// Describe the changes since the previous run.
+    #[must_use]
+    pub fn diff(&self, previous: &Self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for (part, old, new) in [
+            (1, &previous.part1, &self.part1),
+            (2, &previous.part2, &self.part2),
+        ] {
+            if old != new {
+                lines.push(format!("part {part} changed: {old} → {new}"));
+            }
+        }
+        if lines.is_empty() {
+            lines.push("answers unchanged".to_string());
+        }
+
+        if !previous.elapsed.is_zero() {
+            let ratio = self.elapsed.as_secs_f64() / previous.elapsed.as_secs_f64();
+            lines.push(format!(
+                "elapsed: {:#?} → {:#?} ({:+.0}%)",
+                previous.elapsed,
+                self.elapsed,
+                (ratio - 1.) * 100.
+            ));
+        }
+
+        lines
+    }
+}
+
+/// Rebuild the runner with the profile and the features of the running one.
+///
+/// Returns `true` if the build succeeded.
+#[must_use]
+pub fn rebuild(root: &Path) -> bool {
+    let mut cargo = Command::new("cargo");
+    cargo.arg("build").current_dir(root);
+
+    if env!("AOR_PROFILE") == "release" {
+        cargo.arg("--release");
+    }
+
+    let features = crate::timings::enabled_features();
+    if !features.is_empty() {
+        cargo.arg("--features").arg(features.join(","));
+    }
+
+    cargo.status().is_ok_and(|status| status.success())
+}
+
+/// Replace the running process with the rebuilt `exe`, with the same arguments.
+pub fn restart(exe: &Path, previous: Option<&Answers>) -> ! {
+    let mut command = Command::new(exe);
+    command.args(std::env::args_os().skip(1));
+
+    if let Some(previous) = previous {
+        command.env(PREVIOUS_VAR, previous.to_json());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = command.exec();
+        eprintln!("cannot restart {}: {err}", exe.display());
+        std::process::exit(1);
+    }
+
+    #[cfg(not(unix))]
+    {
+        let status = command.status();
+        std::process::exit(status.map_or(1, |status| status.code().unwrap_or(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes() {
+        let dir = std::env::temp_dir().join(format!("aor-watch-{}", std::process::id()));
+        let source = dir.join("day1.rs");
+        let input = dir.join("input.txt");
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&source, "fn main() {}").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.clone(), input.clone()], Duration::ZERO);
+        assert!(watcher.changes().is_empty());
+
+        let file = std::fs::File::options().write(true).open(&source).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        std::fs::write(&input, "(()").unwrap();
+        assert_eq!(watcher.wait(), vec![source, input.clone()]);
+
+        std::fs::remove_file(&input).unwrap();
+        assert_eq!(watcher.changes(), vec![input]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = Answers {
+            part1: "74".to_string(),
+            part2: "1795".to_string(),
+            elapsed: Duration::from_millis(4),
+        };
+        let answers = Answers {
+            part2: "1796".to_string(),
+            elapsed: Duration::from_millis(3),
+            ..previous.clone()
+        };
+
+        assert_eq!(
+            answers.diff(&previous),
+            vec!["part 2 changed: 1795 → 1796", "elapsed: 4ms → 3ms (-25%)"]
+        );
+        assert_eq!(
+            previous.diff(&previous),
+            vec!["answers unchanged", "elapsed: 4ms → 4ms (+0%)"]
+        );
+    }
+}
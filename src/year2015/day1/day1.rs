@@ -22,6 +22,17 @@ pub fn solve(data: &str) -> (i32, i32) {
     (floor, enter)
 }
 
+/// Check that the input is only made of parentheses.
+/// # Errors
+/// On the first character that is not a parenthesis.
+pub fn validate(data: &str) -> Result<(), aoc::InputError> {
+    data.trim_ascii_end()
+        .find(|c| c != '(' && c != ')')
+        .map_or(Ok(()), |offset| {
+            Err(aoc::InputError::at(data, offset, "expected ( or )"))
+        })
+}
+
 /// Main function.
 pub fn main() {
     let args = aoc::parse_args();
@@ -55,4 +66,13 @@ mod test {
         assert_eq!(solve(")").1, 1);
         assert_eq!(solve("()())").1, 5);
     }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("(()))\n"), Ok(()));
+        assert_eq!(
+            validate("(()x)\n"),
+            Err(aoc::InputError::new(1, 4, "expected ( or )"))
+        );
+    }
 }
@@ -3,14 +3,14 @@
 use regex::Regex;
 use rustc_hash::FxHashMap;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     aunts: FxHashMap<u32, FxHashMap<&'a str, u32>>,
 }
 
 impl<'a> Puzzle<'a> {
     /// Parse the input data using manual string parsing (faster than regex).
     /// Parses lines in the format: "Sue 1: goldfish: 6, trees: 9, akitas: 0"
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         let mut aunts: FxHashMap<u32, FxHashMap<&'a str, u32>> = FxHashMap::default();
 
         for line in data.lines() {
@@ -75,7 +75,7 @@ impl<'a> Puzzle<'a> {
         Self { aunts }
     }
 
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         for (sue, aunt) in &self.aunts {
             if aunt.get("children").unwrap_or(&3) == &3
                 && aunt.get("cats").unwrap_or(&7) == &7
@@ -94,7 +94,7 @@ impl<'a> Puzzle<'a> {
         0
     }
 
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         for (sue, aunt) in &self.aunts {
             if aunt.get("children").unwrap_or(&3) == &3
                 && aunt.get("cats").unwrap_or(&8) > &7          // should be greater than
@@ -128,7 +128,10 @@ pub fn solve_regex(data: &str) -> (u32, u32) {
 }
 
 pub fn main() {
-    let args = aoc::parse_args();
+    let args = aoc::parse_args_with(&[aoc::Opt::flag(
+        "--use-regex",
+        "Parse the input with regexes",
+    )]);
     if args.has_option("--use-regex") {
         args.run(solve_regex);
     } else {
@@ -2,13 +2,13 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     replacements: Vec<(String, String)>,
     medicine_molecule: String,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut replacements = vec![];
         let mut medicine_molecule = String::new();
 
@@ -43,7 +43,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut molecules = FxHashSet::default();
 
         for (from, to) in &self.replacements {
@@ -56,7 +56,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         // formulae
         // https://github.com/petertseng/adventofcode-rb-2015/blob/e968bc59e527e47ca9a28b313f58cc04b6f074cb/19_molecule_replacement.rb#L54
         // I don't know if there's an algorithm to solve this problem 😕
@@ -44,6 +44,28 @@ pub fn solve(data: &str) -> (u32, u32) {
     (total_paper, total_ribbon)
 }
 
+/// Check that each line of the input is made of three dimensions, like `2x3x4`.
+/// # Errors
+/// On the first dimension that is not a number, or the first line without three dimensions.
+pub fn validate(data: &str) -> Result<(), aoc::InputError> {
+    for (index, line) in data.lines().enumerate() {
+        let mut column = 1;
+
+        for dimension in line.split('x') {
+            if dimension.parse::<u32>().is_err() {
+                return Err(aoc::InputError::new(index + 1, column, "expected a number"));
+            }
+            column += dimension.len() + 1;
+        }
+
+        if line.split('x').count() != 3 {
+            return Err(aoc::InputError::new(index + 1, 1, "expected LxWxH"));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn main() {
     let args = aoc::parse_args();
     args.run(solve);
@@ -64,4 +86,17 @@ mod test {
         assert_eq!(solve("2x3x4").1, 34);
         assert_eq!((solve("1x1x10").1), 14);
     }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("2x3x4\n1x1x10\n"), Ok(()));
+        assert_eq!(
+            validate("2x3x4\n1x1\n"),
+            Err(aoc::InputError::new(2, 1, "expected LxWxH"))
+        );
+        assert_eq!(
+            validate("2x3x4\n1xax10\n"),
+            Err(aoc::InputError::new(2, 3, "expected a number"))
+        );
+    }
 }
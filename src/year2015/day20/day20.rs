@@ -2,18 +2,18 @@
 
 use rayon::prelude::*;
 
-struct Puzzle {
+pub struct Puzzle {
     house_present: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             house_present: data.trim_ascii().parse::<usize>().unwrap(),
         }
     }
 
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         const CHUNK_SIZE: usize = 65_536;
 
         // Estimate upper bound for chunks
@@ -48,7 +48,7 @@ impl Puzzle {
         result.unwrap_or(0)
     }
 
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         const CHUNK_SIZE: usize = 262_144; // 2^18
         let mut houses = vec![0; CHUNK_SIZE];
         let mut start_idx = 1;
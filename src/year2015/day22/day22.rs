@@ -143,13 +143,13 @@ impl State {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     boss_hp: i32,
     boss_dmg: i32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut boss_hp = 0;
         let mut boss_dmg = 0;
 
@@ -201,12 +201,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         self.play(false)
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         self.play(true)
     }
 }
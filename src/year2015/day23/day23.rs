@@ -28,12 +28,12 @@ impl IndexMut<&str> for Registers {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     program: Vec<Vec<String>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             program: data
                 .lines()
@@ -96,12 +96,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.run(0).b
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.run(1).b
     }
 }
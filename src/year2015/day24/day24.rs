@@ -2,12 +2,12 @@
 
 use itertools::Itertools;
 
-struct Puzzle {
+pub struct Puzzle {
     packages: Vec<u64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             packages: data.lines().filter_map(|s| s.parse().ok()).collect(),
         }
@@ -32,12 +32,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.solve(3)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve(4)
     }
 }
@@ -1,4 +1,5 @@
 //! [Day 4: The Ideal Stocking Stuffer](https://adventofcode.com/2015/day/4)
+// tags: slow
 
 use rayon::prelude::*;
 
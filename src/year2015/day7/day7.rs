@@ -1,4 +1,5 @@
 //! [Day 7: Some Assembly Required](https://adventofcode.com/2015/day/7)
+// tags: -vm
 
 use regex::Regex;
 use rustc_hash::FxHashMap;
@@ -1,4 +1,5 @@
 //! [Day 11: Radioisotope Thermoelectric Generators](https://adventofcode.com/2016/day/11)
+// tags: graph
 
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -146,13 +147,13 @@ impl State {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     initial: State,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut generators = Vec::new();
         let mut microchips = Vec::new();
         let mut elements = FxHashMap::default();
@@ -191,12 +192,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.initial.solve()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut new_items = self.initial.clone();
 
         let m = new_items.n();
@@ -1,4 +1,5 @@
 //! [Day 13: A Maze of Twisty Little Cubicles](https://adventofcode.com/2016/day/13)
+// tags: graph
 
 use rustc_hash::FxHashSet;
 use std::collections::VecDeque;
@@ -76,24 +77,24 @@ fn bfs(designer_number: u32, start: (u32, u32), end: (u32, u32), max_moves: usiz
     0
 }
 
-struct Puzzle {
+pub struct Puzzle {
     designer_number: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             designer_number: data.trim().parse().unwrap(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         bfs(self.designer_number, (1, 1), (31, 39), usize::MAX)
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         bfs(self.designer_number, (1, 1), (u32::MAX, u32::MAX), 50)
     }
 }
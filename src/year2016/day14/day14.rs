@@ -1,4 +1,5 @@
 //! [Day 14: One-Time Pad](https://adventofcode.com/2016/day/14)
+// tags: slow
 
 use rayon::prelude::*;
 
@@ -1,20 +1,21 @@
 //! [Day 17: Two Steps Forward](https://adventofcode.com/2016/day/17)
+// tags: graph
 
 use std::collections::VecDeque;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     password: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             password: data.trim_ascii(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut base_digest = md5::Context::new();
 
         base_digest.consume(self.password);
@@ -64,7 +65,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut max_steps = 0;
 
         let mut base_digest = md5::Context::new();
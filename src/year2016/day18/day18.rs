@@ -1,12 +1,12 @@
 //! [Day 18: Like a Rogue](https://adventofcode.com/2016/day/18)
 
-struct Puzzle {
+pub struct Puzzle {
     trap_bits: u128,
     width: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let s = data.trim_ascii();
         let width = u32::try_from(s.len()).unwrap();
         assert!(width <= 128, "input too wide for u128 bitset");
@@ -36,12 +36,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.solve(40)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.solve(400_000)
     }
 }
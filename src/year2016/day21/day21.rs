@@ -101,12 +101,12 @@ impl Operation {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     ops: Vec<Operation>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut ops = Vec::new();
 
         let re1 = Regex::new(r"rotate based on position of letter (\w)").unwrap();
@@ -152,7 +152,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut password: Vec<_> = "abcdefgh".chars().collect();
 
         for op in &self.ops {
@@ -163,7 +163,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut password: Vec<_> = "fbgdceah".chars().collect();
 
         for op in self.ops.iter().rev() {
@@ -10,14 +10,14 @@ struct Node {
     avail: u32,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     nodes: Vec<Node>,
     width: u32,
     height: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut nodes = vec![];
         let mut width = 0;
         let mut height = 0;
@@ -75,7 +75,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut viable = 0;
 
         for (i, a) in self.nodes.iter().enumerate() {
@@ -90,7 +90,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut empty_x = 0;
         let mut empty_y = 0;
         let mut wall_x = u32::MAX;
@@ -1,4 +1,5 @@
 //! [Day 24: Air Duct Spelunking](https://adventofcode.com/2016/day/24)
+// tags: graph
 
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
@@ -11,12 +12,12 @@ fn digit_usize(c: u8) -> Option<usize> {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     grid: aoc::GridU<u8>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: aoc::GridU::<u8>::parse(data),
         }
@@ -73,12 +74,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.solve().0
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.solve().1
     }
 }
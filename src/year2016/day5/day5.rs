@@ -1,4 +1,5 @@
 //! [Day 5: How About a Nice Game of Chess?](https://adventofcode.com/2016/day/5)
+// tags: slow
 
 use rayon::prelude::*;
 use std::sync::Arc;
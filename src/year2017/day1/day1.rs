@@ -1,11 +1,11 @@
 //! [Day 1: Inverse Captcha](https://adventofcode.com/2017/day/1)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             data: data.trim_ascii(),
         }
@@ -28,11 +28,11 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.compute(1)
     }
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.compute(self.data.len() / 2)
     }
 }
@@ -2,19 +2,19 @@
 
 use aoc::knot;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             data: data.trim_ascii(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let lengths: Vec<_> = self
             .data
             .split(',')
@@ -31,7 +31,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         knot::hash(self.data)
     }
 }
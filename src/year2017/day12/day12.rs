@@ -1,15 +1,16 @@
 //! [Day 12: Digital Plumber](https://adventofcode.com/2017/day/12)
+// tags: graph
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
-struct Puzzle {
+pub struct Puzzle {
     links: FxHashMap<u32, Vec<u32>>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut links = FxHashMap::default();
 
         for line in data.lines() {
@@ -41,12 +42,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.walk(0).len()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut programs: FxHashSet<u32> = self.links.keys().copied().collect();
         let mut groups = 0;
         while let Some(id) = programs.iter().next() {
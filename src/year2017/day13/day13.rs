@@ -2,12 +2,12 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     heights: FxHashMap<u32, u32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut heights = FxHashMap::default();
 
         for line in data.lines() {
@@ -23,7 +23,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.heights
             .iter()
             .filter(|&(&pos, &height)| pos % (2 * (height - 1)) == 0)
@@ -32,7 +32,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         (0..10_000_000)
             .find(|wait| {
                 !self
@@ -14,19 +14,19 @@ const fn count_ones(value: u8) -> u32 {
     count
 }
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     key: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             key: data.trim_ascii(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         (0..128)
             .map(|i| {
                 knot::hash_raw(format!("{}-{i}", self.key).as_str())
@@ -39,7 +39,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut grid: Grid<u8> = Grid::<u8>::with_size(128, 128, 0, 0);
 
         for y in 0..128 {
@@ -7,7 +7,7 @@ const MODULUS: u64 = 0x7fff_ffff;
 const FACTOR_A: u64 = 16807;
 const FACTOR_B: u64 = 48271;
 
-struct Puzzle {
+pub struct Puzzle {
     a: u64,
     b: u64,
 }
@@ -36,7 +36,7 @@ const fn mod_pow(mut base: u64, mut exp: u64) -> u64 {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut a = 0;
         let mut b = 0;
 
@@ -52,7 +52,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         (0_u64..40_000_000_u64)
             .step_by(CHUNK)
             .par_bridge()
@@ -73,7 +73,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         const N: usize = 5_000_000;
         let (start_a, start_b) = (self.a, self.b);
 
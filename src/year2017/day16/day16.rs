@@ -1,11 +1,11 @@
 //! [Day 16: Permutation Promenade](https://adventofcode.com/2017/day/16)
 
-struct Puzzle {
+pub struct Puzzle {
     program: Vec<String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             program: data
                 .trim()
@@ -40,7 +40,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut text: Vec<u8> = "abcdefghijklmnop".bytes().collect();
 
         self.dance(&mut text);
@@ -49,7 +49,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut text: Vec<u8> = "abcdefghijklmnop".bytes().collect();
         let initial = text.clone();
 
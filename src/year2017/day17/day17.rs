@@ -1,18 +1,18 @@
 //! [Day 17: Spinlock](https://adventofcode.com/2017/day/17)
 
-struct Puzzle {
+pub struct Puzzle {
     step: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             step: data.trim().parse().unwrap(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut buf = vec![0];
         let mut pos = 0;
 
@@ -25,7 +25,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    const fn part2(&self) -> usize {
+    pub const fn part2(&self) -> usize {
         let step = self.step + 1;
         let mut buflen: usize = 1;
         let mut pos = 0;
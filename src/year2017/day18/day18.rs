@@ -102,12 +102,12 @@ impl Program {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     program: Vec<(String, Vec<String>)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             program: data
                 .lines()
@@ -125,7 +125,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         let mut p = Program::new(0, &self.program);
 
         p.mode_sound = true;
@@ -134,7 +134,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut p0 = Program::new(0, &self.program);
         let mut p1 = Program::new(1, &self.program);
 
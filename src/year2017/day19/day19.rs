@@ -2,14 +2,14 @@
 
 use aoc::Coord;
 
-struct Puzzle {
+pub struct Puzzle {
     path: String,
     steps: u32,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let   grid = aoc::Grid::<char>::parse(data, ' ');
 
 
@@ -54,12 +54,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         self.path.clone()
     }
 
     /// Solve part two.
-    const fn part2(&self) -> u32 {
+    pub const fn part2(&self) -> u32 {
         self.steps
     }
 }
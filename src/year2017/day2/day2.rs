@@ -1,11 +1,11 @@
 //! [Day 2: Corruption Checksum](https://adventofcode.com/2017/day/2)
 
-struct Puzzle {
+pub struct Puzzle {
     rows: Vec<Vec<u32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut rows = vec![];
         for line in data.lines() {
             rows.push(
@@ -18,7 +18,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.rows
             .iter()
             .map(|row| row.iter().max().unwrap() - row.iter().min().unwrap())
@@ -26,7 +26,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.rows
             .iter()
             .map(|row| {
@@ -17,13 +17,13 @@ struct Particle {
     az: i64,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     particles: Vec<Particle>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let re = Regex::new(r"^p=<(-?\d+),(-?\d+),(-?\d+)>, v=<(-?\d+),(-?\d+),(-?\d+)>, a=<(-?\d+),(-?\d+),(-?\d+)>$").unwrap();
 
         let particles = data
@@ -49,7 +49,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let t = 1000;
 
         let mut min_dist = i64::MAX;
@@ -70,7 +70,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut particles = self.particles.clone();
 
         for t in 0..1000 {
@@ -4,14 +4,14 @@ use rustc_hash::FxHashMap;
 
 type Square = aoc::Square<u8>;
 
-struct Puzzle {
+pub struct Puzzle {
     rules: FxHashMap<Square, Square>,
     start: Square,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut p = Self {
             rules: FxHashMap::default(),
             start: Square::parse(".#./..#/###", '/'),
@@ -67,12 +67,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.solve(5)
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.solve(18)
     }
 }
@@ -18,7 +18,7 @@ enum State {
     Flagged,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     infected: FxHashSet<(i32, i32)>,
     nx: i32, // size of the map
     ny: i32,
@@ -26,7 +26,7 @@ struct Puzzle {
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut infected = FxHashSet::default();
         let mut nx = 0;
         let mut ny = 0;
@@ -45,7 +45,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut infected = self.infected.clone();
 
         let mut x = self.nx / 2; // middle of the map
@@ -73,7 +73,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut nodes = self
             .infected
             .iter()
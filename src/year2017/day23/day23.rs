@@ -63,12 +63,12 @@ impl Program {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     program: Vec<(String, Vec<String>)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             program: data
                 .lines()
@@ -86,7 +86,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut p = Program::new(&self.program);
 
         p.run();
@@ -95,7 +95,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut p = Program::new(&self.program);
 
         p.regs.insert("a".to_string(), 1);
@@ -1,18 +1,18 @@
 //! [Day 3: Spiral Memory](https://adventofcode.com/2017/day/3)
 
-struct Puzzle {
+pub struct Puzzle {
     n: i32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             n: data.trim().parse().unwrap(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut x = 0;
         let mut y = 0;
         let mut m = 1;
@@ -47,7 +47,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut grid = [[0; 11]; 11];
 
         let offset = |x: i32| usize::try_from(x + 5).unwrap();
@@ -2,19 +2,19 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     lines: Vec<String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             lines: data.lines().map(str::to_string).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.lines
             .iter()
             .filter(|line| {
@@ -27,7 +27,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.lines
             .iter()
             .filter(|line| {
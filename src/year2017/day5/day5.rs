@@ -1,18 +1,18 @@
 //! [Day 5: A Maze of Twisty Trampolines, All Alike](https://adventofcode.com/2017/day/5)
 
-struct Puzzle {
+pub struct Puzzle {
     jumps: Vec<i32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             jumps: data.lines().map(|s| s.parse().unwrap()).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut jumps = self.jumps.clone();
         let length = i32::try_from(jumps.len()).unwrap();
         let mut offset = 0;
@@ -32,7 +32,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut jumps = self.jumps.clone();
         let length = i32::try_from(jumps.len()).unwrap();
         let mut offset = 0;
@@ -51,7 +51,7 @@ impl std::fmt::Display for NodeId {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     root: NodeId,
     children: FxHashMap<NodeId, Vec<NodeId>>,
     nodes: FxHashMap<NodeId, u32>,
@@ -59,7 +59,7 @@ struct Puzzle {
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut all_children = FxHashSet::default();
         let mut children = FxHashMap::default();
         let mut nodes = FxHashMap::default();
@@ -127,12 +127,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         self.root.as_string()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.traverse(self.root).1
     }
 }
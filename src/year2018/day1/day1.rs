@@ -2,22 +2,22 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         self.data.lines().map(|x| x.parse::<i32>().unwrap()).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut frequencies = FxHashSet::default();
         let mut sum = 0;
         loop {
@@ -2,12 +2,12 @@
 
 use rayon::prelude::*;
 
-struct Puzzle {
+pub struct Puzzle {
     serial_number: i32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             serial_number: data.trim().parse().unwrap(),
         }
@@ -42,13 +42,13 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let (x, y, _) = self.square_power(3);
         format!("{x},{y}")
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let max = (3..300)
             .par_bridge()
             .map(|size| {
@@ -2,13 +2,13 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     state: String,
     rules: FxHashSet<Vec<char>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut state = String::new();
         let mut rules = FxHashSet::default();
 
@@ -24,7 +24,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut state = self.state.chars().collect::<Vec<_>>();
 
         let mut pots = 0;
@@ -70,7 +70,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut state = self.state.chars().collect::<Vec<_>>();
 
         let mut score = 0;
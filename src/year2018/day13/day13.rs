@@ -110,13 +110,13 @@ impl Cart {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid,
     carts: Vec<Cart>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut grid = GridU::<char>::parse(data);
         let mut carts = Vec::new();
 
@@ -232,7 +232,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut carts = self.carts.clone();
 
         for _ in 0..100_000 {
@@ -251,7 +251,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut carts = self.carts.clone();
 
         for _ in 0..100_000 {
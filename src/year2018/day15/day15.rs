@@ -1,4 +1,5 @@
 //! [Day 15: Beverage Bandits](https://adventofcode.com/2018/day/15)
+// tags: graph
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{cmp::Ordering, collections::VecDeque};
@@ -29,7 +30,7 @@ impl Unit {
             .then(Self::attack_order(self, other))
     }
 }
-struct Puzzle {
+pub struct Puzzle {
     wall: FxHashSet<(usize, usize)>,
     units: Vec<Unit>,
 }
@@ -136,7 +137,7 @@ fn has_elves_and_goblins(units: &[Unit]) -> bool {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut wall = FxHashSet::default();
         let mut units = vec![];
 
@@ -234,12 +235,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.fight(3, false).unwrap()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         for elf_attack_power in 4..100 {
             if let Some(outcome) = self.fight(elf_attack_power, true) {
                 return outcome;
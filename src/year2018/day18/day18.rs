@@ -1,7 +1,5 @@
 //! [Day 18: Settlers of The North Pole](https://adventofcode.com/2018/day/18)
 
-use rustc_hash::FxHashMap;
-
 const OPEN_ACRE: u8 = 0;
 const TREE: u8 = 1;
 const LUMBERYARD: u8 = 2;
@@ -72,12 +70,12 @@ fn hashable(area: &Area) -> Vec<u8> {
     s
 }
 
-struct Puzzle {
+pub struct Puzzle {
     area: Area,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut area = Area::default();
         let mut n = 0;
 
@@ -98,7 +96,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut area = self.area.clone();
 
         for _ in 0..10 {
@@ -109,30 +107,15 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
-        let mut area = self.area.clone();
-
-        let mut values = vec![];
-        let mut seen = FxHashMap::default();
-
-        for i in 0.. {
-            values.push(value(&area));
-
-            if seen.contains_key(&hashable(&area)) {
-                let cycle_start = *seen.get(&hashable(&area)).unwrap();
-                let cycle_end = i;
-
-                let n: usize = 1_000_000_000;
-                let cycle = cycle_end - cycle_start;
-                return values[cycle_start + (n - cycle_end) % cycle];
-            }
-
-            seen.insert(hashable(&area), i);
-
-            area = collect(&area);
-        }
+    pub fn part2(&self) -> u32 {
+        let area = aoc::cycle::nth(
+            self.area.clone(),
+            |area| *area = collect(area),
+            hashable,
+            1_000_000_000,
+        );
 
-        0
+        value(&area)
     }
 }
 
@@ -49,13 +49,13 @@ impl std::fmt::Display for Instr {
 
 type Program = Vec<Instr>;
 
-struct Puzzle {
+pub struct Puzzle {
     ip_reg: usize,
     program: Program,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut ip_reg = 0;
         let mut program = vec![];
 
@@ -123,12 +123,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.solve_optimized(0)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.solve_optimized(1)
     }
 }
@@ -1,16 +1,16 @@
 //! [Day 2: Inventory Management System](https://adventofcode.com/2018/day/2)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut two = 0;
         let mut three = 0;
         for line in self.data.lines() {
@@ -31,7 +31,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         for l in self.data.lines() {
             for r in self.data.lines() {
                 let same: String = l
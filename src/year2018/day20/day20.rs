@@ -1,4 +1,5 @@
 //! [Day 20: A Regular Map](https://adventofcode.com/2018/day/20)
+// tags: graph
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
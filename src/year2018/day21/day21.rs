@@ -244,7 +244,10 @@ pub fn solve(data: &str) -> (u64, u64) {
 }
 
 pub fn main() {
-    let args = aoc::parse_args();
+    let args = aoc::parse_args_with(&[aoc::Opt::flag(
+        "--emulate",
+        "Run the program on the emulator",
+    )]);
 
     if args.has_option("--emulate") {
         Puzzle::new(args.input()).run(1);
@@ -1,4 +1,5 @@
 //! [Day 22: Mode Maze](https://adventofcode.com/2018/day/22)
+// tags: graph
 
 use aoc::Direction;
 use rustc_hash::{FxHashMap, FxHashSet};
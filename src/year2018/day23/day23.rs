@@ -140,12 +140,12 @@ impl Ord for Cost {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     nanobots: Vec<Nanobot>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut nanobots = vec![];
 
         let re = Regex::new(r"^pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(-?\d+)$").unwrap();
@@ -165,7 +165,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let strongest = self.nanobots.iter().max_by_key(|a| a.r).unwrap();
 
         self.nanobots
@@ -174,7 +174,7 @@ impl Puzzle {
             .count()
     }
 
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut heap = BinaryHeap::new();
 
         heap.push(Cost {
@@ -30,19 +30,19 @@ impl Nanobot {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     nanobots: Vec<Nanobot>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             nanobots: data.lines().map(Nanobot::from).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let strongest = self.nanobots.iter().max_by_key(|a| a.r).unwrap();
 
         self.nanobots
@@ -52,7 +52,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let ooo = z3::Optimize::new();
 
         let x = Int::new_const("x");
@@ -7,12 +7,12 @@ mod group;
 
 use combat::Combat;
 
-struct Puzzle {
+pub struct Puzzle {
     combat: Combat,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let (army1, army2) = data.split_once("\n\n").unwrap();
 
         Self {
@@ -21,14 +21,14 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let combat = self.combat.clone();
 
         combat.fight_to_death()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut a = 0;
         let mut b = 2000;
 
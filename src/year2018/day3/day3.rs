@@ -3,17 +3,17 @@
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let re = Regex::new(r"^#([0-9]+) @ ([0-9]+),([0-9]+): ([0-9]+)x([0-9]+)$").unwrap();
 
         let mut squares = FxHashMap::default();
@@ -44,7 +44,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let re = Regex::new(r"^#([0-9]+) @ ([0-9]+),([0-9]+): ([0-9]+)x([0-9]+)$").unwrap();
 
         let mut squares_id: FxHashMap<(u32, u32), u32> = FxHashMap::default();
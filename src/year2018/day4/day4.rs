@@ -2,13 +2,13 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     /// Number of minutes asleep for each minute from 00:00 to 00:59 by guard ID
     sleeping: FxHashMap<u32, [u32; 60]>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut sleeping = FxHashMap::default();
 
         let mut lines: Vec<_> = data.lines().collect();
@@ -41,7 +41,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let guard_most_asleep = self
             .sleeping
             .iter()
@@ -63,7 +63,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let ((_n, guard), n) = (0..60)
             .map(|m| {
                 (
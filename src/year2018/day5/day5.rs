@@ -28,24 +28,24 @@ fn react_polymer(polymer: &[u8]) -> usize {
     reacted.len()
 }
 
-struct Puzzle {
+pub struct Puzzle {
     polymer: Vec<u8>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             polymer: data.trim().bytes().collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         react_polymer(&self.polymer)
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         (b'a'..=b'z')
             .map(|unit| {
                 let mut polymer = self.polymer.clone();
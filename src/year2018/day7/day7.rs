@@ -2,12 +2,12 @@
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-struct Puzzle {
+pub struct Puzzle {
     deps: FxHashMap<char, FxHashSet<char>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut deps: FxHashMap<char, FxHashSet<char>> = FxHashMap::default();
 
         for line in data.lines() {
@@ -25,7 +25,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut result = String::new();
 
         let n = self.deps.len();
@@ -120,7 +120,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.solve_part2(5, 60)
     }
 }
@@ -1,11 +1,11 @@
 //! [Day 8: Memory Maneuver](https://adventofcode.com/2018/day/8)
 
-struct Puzzle {
+pub struct Puzzle {
     nodes: Vec<usize>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             nodes: data
                 .trim()
@@ -57,12 +57,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.solve(0, true).1
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.solve(0, false).1
     }
 }
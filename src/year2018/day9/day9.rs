@@ -39,13 +39,13 @@ fn play_fast(nb_players: u32, marbles: u32) -> u32 {
     scores.values().copied().max().unwrap()
 }
 
-struct Puzzle {
+pub struct Puzzle {
     elves: u32,
     points: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let row = data.split_ascii_whitespace().collect::<Vec<_>>();
         match &row[..] {
             [n, _, _, _, _, _, p, _] => Self {
@@ -57,12 +57,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         play_slow(self.elves, self.points)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         play_fast(self.elves, self.points * 100)
     }
 }
@@ -28,13 +28,13 @@ fn play(nb_players: u32, nb_marbles: u32) -> u32 {
     *scores.iter().max().unwrap()
 }
 
-struct Puzzle {
+pub struct Puzzle {
     elves: u32,
     points: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let row = data.split_ascii_whitespace().collect::<Vec<_>>();
         match &row[..] {
             [n, _, _, _, _, _, p, _] => Self {
@@ -46,12 +46,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         play(self.elves, self.points)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         c_solve(self.elves, self.points * 100)
     }
 }
@@ -1,23 +1,23 @@
 //! [Day 1: The Tyranny of the Rocket Equation](https://adventofcode.com/2019/day/1)
 
-struct Puzzle {
+pub struct Puzzle {
     data: Vec<i32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             data: data.lines().map(|line| line.parse().unwrap()).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         self.data.iter().map(|mass| mass / 3 - 2).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut answer = 0;
         for mass in &self.data {
             let mut fuel = *mass;
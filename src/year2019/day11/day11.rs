@@ -110,27 +110,27 @@ impl Robot {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     brain: Computer,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             brain: Computer::load(data),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut robot = Robot::new(&self.brain);
         robot.paint(Color::Black);
         robot.panel.len()
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut robot = Robot::new(&self.brain);
         robot.paint(Color::White);
         robot.drawing()
@@ -56,13 +56,13 @@ fn compute_energy(moons: &[Coord], steps: usize) -> i32 {
     system_energy(&moons, &velocities)
 }
 
-struct Puzzle {
+pub struct Puzzle {
     moons: Vec<Coord>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let re = regex::Regex::new(r"<x=(-?\d+), y=(-?\d+), z=(-?\d+)>").unwrap();
 
         let moons = data
@@ -80,12 +80,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         compute_energy(&self.moons, 1000)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut moons = self.moons.clone();
         let mut velocities = vec![ZERO; moons.len()];
 
@@ -41,13 +41,13 @@ impl<'a> Reaction<'a> {
     }
 }
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     formulae: Formulae<'a>,
 }
 
 impl<'a> Puzzle<'a> {
     /// Initialize from the puzzle input.
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         let mut formulae = FxHashMap::default();
 
         for formula in data.lines() {
@@ -72,7 +72,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         let mut c = Reaction {
             excess: FxHashMap::default(),
             formulae: &self.formulae,
@@ -82,7 +82,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut c = Reaction {
             excess: FxHashMap::default(),
             formulae: &self.formulae,
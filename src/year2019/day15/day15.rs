@@ -1,4 +1,5 @@
 //! [Day 15: Oxygen System](https://adventofcode.com/2019/day/15)
+// tags: graph
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
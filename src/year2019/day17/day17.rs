@@ -115,14 +115,14 @@ impl<'a> PathCompressor<'a> {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     aft: Computer,
     grid: Grid<u8>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut aft = Computer::load(data);
 
         let mut scaffold = Vec::new();
@@ -143,7 +143,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut grid = self.grid.clone();
         let mut result = 0;
 
@@ -165,7 +165,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mars = pathfinder(&self.grid);
 
         let pc = PathCompressor::new(&mars);
@@ -1,4 +1,5 @@
 //! [Day 18: Many-Worlds Interpretation](https://adventofcode.com/2019/day/18)
+// tags: graph
 
 mod mazecell;
 mod mazette;
@@ -60,27 +60,27 @@ impl Scanner {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     drone: Computer,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             drone: Computer::load(data),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         let mut scanner = Scanner::new(&self.drone, N);
 
         (0..50).filter_map(|y| scanner.scan_row(y)).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut scanner = Scanner::new(&self.drone, 50);
 
         for y in 0..N {
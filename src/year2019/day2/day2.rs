@@ -1,6 +1,6 @@
 //! [Day 2: 1202 Program Alarm](https://adventofcode.com/2019/day/2)
 
-struct Puzzle {
+pub struct Puzzle {
     program: Vec<u32>,
 }
 
@@ -8,7 +8,7 @@ const ADD: u32 = 1;
 const MUL: u32 = 2;
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             program: data
                 .trim_ascii()
@@ -47,7 +47,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut memory = self.program.clone();
         memory[1] = 12;
         memory[2] = 2;
@@ -58,7 +58,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         for noun in 0..100 {
             for verb in 0..100 {
                 let mut memory = self.program.clone();
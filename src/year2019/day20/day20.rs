@@ -1,4 +1,5 @@
 //! [Day 20: Donut Maze](https://adventofcode.com/2019/day/20)
+// tags: graph
 
 use aoc::Coord;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -9,7 +10,7 @@ type Portal = [u8; 2];
 const AA: Portal = [b'A', b'A'];
 const ZZ: Portal = [b'Z', b'Z'];
 
-struct Puzzle {
+pub struct Puzzle {
     maze: FxHashSet<Coord>,
     width: i32,
     height: i32,
@@ -21,7 +22,7 @@ struct Puzzle {
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let grid = aoc::Grid::<u8>::parse(data);
         let mut letters = FxHashMap::default();
 
@@ -93,7 +94,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut teleports = FxHashMap::default();
 
         for v in self.portals.values() {
@@ -131,7 +132,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         // init inner and outer portals
         let mut inner_portals = FxHashMap::default();
         let mut inner_portals_inv = FxHashMap::default();
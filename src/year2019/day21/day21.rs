@@ -2,13 +2,13 @@
 
 use intcode::{Computer, State};
 
-struct Puzzle {
+pub struct Puzzle {
     springdroid: Computer,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             springdroid: Computer::load(data),
         }
@@ -33,7 +33,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         self.run_script(concat!(
             "NOT A J\n",
             "NOT C T\n",
@@ -44,7 +44,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         self.run_script(concat!(
             "NOT B J\n",
             "NOT C T\n",
@@ -87,12 +87,12 @@ impl Shuffle {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     shuffles: Vec<Shuffle>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             shuffles: data
                 .lines()
@@ -112,7 +112,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i128 {
+    pub fn part1(&self) -> i128 {
         // let mut deck: Vec<u32> = (0..10007).collect();
         // let n = deck.len();
         //
@@ -156,7 +156,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i128 {
+    pub fn part2(&self) -> i128 {
         let m = 119_315_717_514_047;
         self.shuffles
             .iter()
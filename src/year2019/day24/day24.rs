@@ -128,20 +128,20 @@ impl FoldedBugs {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     bugs: Bugs,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             bugs: Bugs(aoc::Grid::<u8>::parse(data)),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut seen = FxHashSet::default();
         let mut bugs = self.bugs.clone();
         loop {
@@ -162,7 +162,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.p2(200)
     }
 }
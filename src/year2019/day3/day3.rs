@@ -48,12 +48,12 @@ const fn manhattan(p: (i32, i32)) -> i32 {
     p.0.abs() + p.1.abs()
 }
 
-struct Puzzle {
+pub struct Puzzle {
     paths: Vec<Vec<Instr>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             paths: data
                 .lines()
@@ -76,7 +76,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let wire0 = draw(&self.paths[0]);
         let wire1 = draw(&self.paths[1]);
 
@@ -88,7 +88,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let wire0 = draw(&self.paths[0]);
         let wire1 = draw(&self.paths[1]);
 
@@ -1,12 +1,12 @@
 //! [Day 4: Secure Container](https://adventofcode.com/2019/day/4)
 
-struct Puzzle {
+pub struct Puzzle {
     a: u32,
     b: u32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let (a, b) = data.trim_ascii().split_once('-').unwrap();
 
         Self {
@@ -16,7 +16,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut result = 0;
 
         for n in self.a..=self.b {
@@ -40,7 +40,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut result = 0;
 
         for n in self.a..=self.b {
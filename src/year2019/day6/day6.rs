@@ -2,12 +2,12 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     orbits: FxHashMap<String, String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut orbits = FxHashMap::default();
 
         for line in data.lines() {
@@ -20,7 +20,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut total = 0;
 
         for orbit in self.orbits.keys() {
@@ -36,7 +36,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut you_orbits = Vec::new();
         let mut san_orbits = Vec::new();
 
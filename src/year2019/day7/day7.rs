@@ -50,20 +50,20 @@ fn run_feedback(amp: &Computer, phases: &[i64]) -> i64 {
     0
 }
 
-struct Puzzle {
+pub struct Puzzle {
     amp: Computer,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             amp: Computer::load(data),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         (0..5)
             .permutations(5)
             .map(|phases| run_amplifiers(&self.amp, &phases))
@@ -72,7 +72,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         (5..10)
             .permutations(5)
             .map(|phases| run_feedback(&self.amp, &phases))
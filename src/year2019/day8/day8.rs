@@ -1,19 +1,19 @@
 //! [Day 8: Space Image Format](https://adventofcode.com/2019/day/8)
 
-struct Puzzle {
+pub struct Puzzle {
     data: Vec<u8>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             data: data.trim_ascii().bytes().collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut layers = Vec::new();
 
         for layer in self.data.chunks(25 * 6) {
@@ -62,7 +62,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         aoc::ocr::scan_5x6(&self.make_image(25, 6))
     }
 }
@@ -1,18 +1,18 @@
 //! [Day 1: Report Repair](https://adventofcode.com/2020/day/1)
 
-struct Puzzle {
+pub struct Puzzle {
     expenses: Vec<u64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             expenses: data.lines().map(|s| s.parse().unwrap()).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         for i in &self.expenses {
             for j in &self.expenses {
                 if i + j == 2020 {
@@ -24,7 +24,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         for i in &self.expenses {
             for j in &self.expenses {
                 for k in &self.expenses {
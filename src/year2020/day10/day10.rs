@@ -2,12 +2,12 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     adapters: Vec<i64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut adapters = Vec::new();
         adapters.extend(data.lines().map_while(|line| line.parse::<i64>().ok()));
         adapters.sort_unstable();
@@ -15,7 +15,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut diffs: FxHashMap<i64, u32> = FxHashMap::default();
 
         for w in self.adapters.windows(2) {
@@ -31,7 +31,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut adapters = self.adapters.clone();
 
         adapters.insert(0, 0); // add the charging outlet
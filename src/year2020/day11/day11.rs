@@ -15,14 +15,14 @@ const NEIGHBORS: &[(i32, i32)] = &[
     (-1, 1),
 ];
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Vec<u8>,
     width: usize,
     height: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let width = data.lines().next().map_or(0, str::len);
         let grid: Vec<u8> = data.lines().flat_map(str::bytes).collect();
         let height = grid.len().checked_div(width).unwrap_or(0);
@@ -101,11 +101,11 @@ impl Puzzle {
         bytecount::count(&current, OCCUPIED)
     }
 
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         Self::simulate(&self.build_adjacency(1), 4)
     }
 
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let vis = i32::try_from(self.width.max(self.height)).expect("fits i32");
         Self::simulate(&self.build_adjacency(vis), 5)
     }
@@ -5,12 +5,12 @@ struct Instruction {
     n: i32,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     instructions: Vec<Instruction>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             instructions: data
                 .lines()
@@ -23,7 +23,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut x = 0;
         let mut y = 0;
         let mut d = 90;
@@ -51,7 +51,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let (mut x, mut y) = (0, 0);
         let (mut wx, mut wy) = (10, 1);
 
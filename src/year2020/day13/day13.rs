@@ -15,13 +15,13 @@ fn chinese_remainder(residues: &[i64], modulii: &[i64]) -> Option<i64> {
     Some(sum.rem_euclid(prod))
 }
 
-struct Puzzle {
+pub struct Puzzle {
     depart: u32,
     buses: Vec<String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let data = data.split_once('\n').unwrap();
 
         Self {
@@ -31,7 +31,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let (minutes, id) = self
             .buses
             .iter()
@@ -46,7 +46,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut residues = vec![];
         let mut modulii = vec![];
 
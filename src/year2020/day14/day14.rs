@@ -2,17 +2,17 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut mem = FxHashMap::default();
         let mut or_mask = 0;
         let mut and_mask = 0;
@@ -34,7 +34,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut mem = FxHashMap::default();
         let mut and_mask = 0;
         let mut x_mask: &str = "";
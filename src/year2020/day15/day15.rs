@@ -5,12 +5,12 @@ fn usize32(_n: u32) -> usize {
     compile_error!("16-bit architecture not supported");
 }
 
-struct Puzzle {
+pub struct Puzzle {
     nums: Vec<u32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             nums: data
                 .trim()
@@ -56,12 +56,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.solve(2020)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.solve(30_000_000)
     }
 }
@@ -13,14 +13,14 @@ struct Field {
     d: u32,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     fields: Vec<Field>,
     your_tickets: Vec<u32>,
     tickets: Vec<Vec<u32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut fields = Vec::new();
         let mut your_tickets = Vec::new();
         let mut tickets = Vec::new();
@@ -74,7 +74,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         // println!("{:?}",self.fields);
 
         let mut error_rate = 0;
@@ -167,7 +167,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve_part2("departure")
     }
 }
@@ -85,12 +85,12 @@ impl Cube {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     cubes: FxHashSet<Cube>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut cubes = FxHashSet::default();
 
         for (y, line) in (0..).zip(data.lines()) {
@@ -105,7 +105,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut cubes = self.cubes.clone();
         for _ in 0..6 {
             cubes = Cube::cycle(&cubes);
@@ -114,7 +114,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut hypercubes = self.cubes.iter().map(Cube::make_4).collect();
 
         for _ in 0..6 {
@@ -7,12 +7,12 @@ struct PolicyPassword {
     password: String,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     data: Vec<PolicyPassword>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             data: data
                 .lines()
@@ -32,7 +32,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.data
             .iter()
             .filter(|pp| {
@@ -43,7 +43,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.data
             .iter()
             .filter(|pp| {
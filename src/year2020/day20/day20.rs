@@ -88,7 +88,7 @@ impl LochNess {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     tiles: FxHashMap<u32, TileArrangement>,
 }
 
@@ -165,7 +165,7 @@ impl Puzzle {
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut tiles = FxHashMap::default();
 
         for tile_def in data.trim_ascii().split("\n\n") {
@@ -195,12 +195,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.corners().0.iter().copied().map(u64::from).product()
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let n = self.tiles.len().sqrt();
         let mut lochness = LochNess::new(n);
 
@@ -9,7 +9,7 @@ struct Menu<'a> {
 }
 
 #[derive(Debug)]
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     menus: Vec<Menu<'a>>,
     allergens: FxHashMap<&'a str, FxHashSet<&'a str>>,
     ingredients: FxHashSet<&'a str>,
@@ -17,7 +17,7 @@ struct Puzzle<'a> {
 
 impl<'a> Puzzle<'a> {
     /// Initialize from the puzzle input.
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         let mut menus = Vec::new();
 
         for line in data.lines() {
@@ -54,7 +54,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut no_allergens = self.ingredients.clone();
 
         for allerg in self.allergens.values() {
@@ -68,7 +68,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut dangerous = Vec::new();
 
         let mut allergens = self.allergens.clone();
@@ -137,14 +137,14 @@ fn recursive_combat(deck1: &Deck, deck2: &Deck, recursive: bool) -> (Deck, Deck)
     (deck1, deck2)
 }
 
-struct Puzzle {
+pub struct Puzzle {
     deck1: Deck,
     deck2: Deck,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let (cards1, cards2) = data.split_once("\n\n").unwrap();
 
         Self {
@@ -154,13 +154,13 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let (win1, win2) = recursive_combat(&self.deck1, &self.deck2, false);
         win1.score() + win2.score()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let (win1, win2) = recursive_combat(&self.deck1, &self.deck2, true);
         win1.score() + win2.score()
     }
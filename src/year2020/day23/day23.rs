@@ -1,11 +1,11 @@
 //! [Day 23: Crab Cups](https://adventofcode.com/2020/day/23)
 
-struct Puzzle {
+pub struct Puzzle {
     cups: Vec<usize>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             cups: data
                 .chars()
@@ -77,12 +77,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.solve(self.cups.len(), 100)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve(1_000_000, 10_000_000)
     }
 }
@@ -46,13 +46,13 @@ impl Walk {
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     tiles: FxHashSet<Coord>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut walk = Walk::new();
 
         for line in data.lines() {
@@ -65,12 +65,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.tiles.len()
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut tiles = self.tiles.clone();
 
         let neighbors = [
@@ -2,12 +2,12 @@
 
 type Grid = aoc::GridU<u8>;
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: Grid::parse(data),
         }
@@ -31,12 +31,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.trees(3, 1)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.trees(1, 1) * self.trees(3, 1) * self.trees(5, 1) * self.trees(7, 1) * self.trees(1, 2)
     }
 }
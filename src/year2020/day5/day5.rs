@@ -24,12 +24,12 @@ fn parse_seat(seat: &str) -> u32 {
     row * 8 + column
 }
 
-struct Puzzle {
+pub struct Puzzle {
     seats: Vec<u32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut seats = Vec::new();
 
         seats.extend(data.lines().map(parse_seat));
@@ -40,12 +40,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         *self.seats.iter().max().unwrap()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         for i in self.seats.windows(2) {
             if i[1] - i[0] == 2 {
                 return i[0] + 1;
@@ -3,13 +3,13 @@
 use regex::Regex;
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     // data: String,
     bags: FxHashMap<String, FxHashMap<String, u32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut bags = FxHashMap::default();
 
         let pat = Regex::new(r"^\s?(\d+) (.+) bags?$").unwrap();
@@ -57,7 +57,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.bags
             .keys()
             .map(|bag| u32::from(self.contains_color(bag, "shiny gold")))
@@ -65,7 +65,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.count_bag("shiny gold")
     }
 }
@@ -28,24 +28,24 @@ fn run(boot_code: &[String]) -> (i32, bool) {
     (acc, ip == boot_code.len())
 }
 
-struct Puzzle {
+pub struct Puzzle {
     boot_code: Vec<String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             boot_code: data.lines().map(std::string::ToString::to_string).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         run(&self.boot_code).0
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut boot_code = self.boot_code.clone();
         for ip in 0..boot_code.len() {
             let instr = &boot_code[ip].clone();
@@ -1,12 +1,12 @@
 //! [Day 9: Encoding Error](https://adventofcode.com/2020/day/9)
 
-struct Puzzle {
+pub struct Puzzle {
     numbers: Vec<u64>,
     window: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             numbers: data
                 .lines()
@@ -17,7 +17,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         'outer: for i in self.window..self.numbers.len() {
             let invalid = self.numbers[i];
 
@@ -35,7 +35,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let invalid = self.part1();
 
         for i in 0..self.numbers.len() {
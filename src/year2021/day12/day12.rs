@@ -1,4 +1,5 @@
 //! [Day 12: Passage Pathing](https://adventofcode.com/2021/day/12)
+// tags: graph
 
 use std::collections::VecDeque;
 
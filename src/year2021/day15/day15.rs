@@ -1,4 +1,5 @@
 //! [Day 15: Chiton](https://adventofcode.com/2021/day/15)
+// tags: graph
 
 use std::collections::BinaryHeap;
 
@@ -88,12 +89,12 @@ fn min_cost(grid: &[Vec<u32>]) -> u32 {
     d[n - 1][n - 1]
 }
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Vec<Vec<u32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: data
                 .lines()
@@ -107,12 +108,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         min_cost(&self.grid) - self.grid[0][0]
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let n = self.grid.len();
 
         // build the five times larger grid
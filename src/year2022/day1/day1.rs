@@ -1,11 +1,11 @@
 //! [Day 1: Calorie Counting](https://adventofcode.com/2022/day/1)
 
-struct Puzzle {
+pub struct Puzzle {
     calories: Vec<usize>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut calories = data
             .trim_ascii()
             .split("\n\n")
@@ -22,11 +22,11 @@ impl Puzzle {
         Self { calories }
     }
 
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.calories[0]
     }
 
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.calories[0..3].iter().sum::<usize>()
     }
 }
@@ -2,13 +2,13 @@
 
 use aoc::ocr::scan_5x6;
 
-struct Puzzle {
+pub struct Puzzle {
     /// Value of X during the `index+1` cycle
     cycles: Vec<i32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut cycles = vec![];
 
         let lines = data.split('\n').collect::<Vec<_>>();
@@ -32,7 +32,7 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut signal_strength = 0;
         for (i, &x) in (0..).zip(&self.cycles) {
             let cycle = i + 1;
@@ -61,7 +61,7 @@ impl Puzzle {
         crt
     }
 
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         scan_5x6(&self.part2_raw())
     }
 }
@@ -38,12 +38,12 @@ struct Monkey {
     if_false: usize,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     monkeys: Vec<Monkey>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut monkeys = Vec::new();
 
         let mut lines = data.trim().lines();
@@ -110,12 +110,12 @@ impl Puzzle {
     }
 
     /// Solves part one
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.solve(20)
     }
 
     /// Solve part two
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve(10000)
     }
 
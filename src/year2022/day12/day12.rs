@@ -1,4 +1,5 @@
 //! [Day 12: Hill Climbing Algorithm](https://adventofcode.com/2022/day/12)
+// tags: graph
 
 use rustc_hash::FxHashSet;
 use std::collections::VecDeque;
@@ -9,14 +10,14 @@ struct Node {
     steps: u32,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     grid: aoc::GridU<u8>,
     start: (usize, usize),
     end: (usize, usize),
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             grid: aoc::GridU::<u8>::parse(data.trim_ascii()),
             start: (0, 0),
@@ -89,12 +90,12 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.bfs(1)
     }
 
     // Solve part two
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.bfs(2)
     }
 }
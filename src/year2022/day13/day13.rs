@@ -54,12 +54,12 @@ impl Packet {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     packets: Vec<Packet>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             packets: data
                 .lines()
@@ -70,7 +70,7 @@ impl Puzzle {
     }
 
     // Solve part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut result = 0;
         for (i, p) in self.packets.chunks(2).enumerate() {
             if p[0].cmp(&p[1]) == Ordering::Less {
@@ -81,7 +81,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut packets = self.packets.clone();
         let divider1 = Packet::new("[[2]]").unwrap().1;
         let divider2 = Packet::new("[[6]]").unwrap().1;
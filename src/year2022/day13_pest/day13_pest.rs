@@ -66,19 +66,19 @@ fn cmp(a: &str, b: &str) -> Ordering {
     inner_cmp(a, b)
 }
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     packets: Vec<&'a str>,
 }
 
 impl<'a> Puzzle<'a> {
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         Self {
             packets: data.lines().filter(|line| !line.is_empty()).collect(),
         }
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut result = 0;
         for (i, chunk) in self.packets.chunks(2).enumerate() {
             let left = &chunk[0];
@@ -91,7 +91,7 @@ impl<'a> Puzzle<'a> {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut a = self.packets.clone();
         a.push("[[2]]");
         a.push("[[6]]");
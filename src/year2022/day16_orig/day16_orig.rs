@@ -3,7 +3,7 @@
 use regex::Regex;
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     valves: FxHashMap<String, usize>,
     flow_rates: FxHashMap<usize, u32>,
     tunnels: FxHashMap<usize, Vec<usize>>,
@@ -11,7 +11,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             valves: FxHashMap::default(),
             flow_rates: FxHashMap::default(),
@@ -161,13 +161,13 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut seen = FxHashMap::default();
         self.max_flow(self.valve_id("AA"), 0, 30, &mut seen)
     }
 
     // Solve part two
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let start_valve = self.valve_id("AA");
 
         let mut best = 0;
@@ -3,12 +3,12 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
-struct Puzzle {
+pub struct Puzzle {
     cubes: FxHashSet<(i32, i32, i32)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut cubes = FxHashSet::default();
 
         for line in data.lines() {
@@ -25,7 +25,7 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut faces = FxHashMap::default();
 
         // each 1x1x1 cube has - obviously - six faces: each face is identified by its center
@@ -51,7 +51,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         // main idea: do a DFS between a known empty cell of the englobing parallelepiped to find
         // all accessible (non trapped) empty cells
 
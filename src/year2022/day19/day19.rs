@@ -220,25 +220,25 @@ impl Blueprint {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     blueprints: Vec<Blueprint>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             blueprints: data.lines().map(Blueprint::new).collect(),
         }
     }
 
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.blueprints
             .par_iter()
             .map(|blueprint| blueprint.solve(24) * blueprint.id)
             .sum()
     }
 
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.blueprints
             .iter()
             .take(3)
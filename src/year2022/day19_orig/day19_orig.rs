@@ -225,19 +225,19 @@ impl Blueprint {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     blueprints: Vec<Blueprint>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             blueprints: data.lines().map(Blueprint::new).collect(),
         }
     }
 
     // Solves part one
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.blueprints
             .iter()
             .map(|blueprint| blueprint.solve(24) * blueprint.id)
@@ -245,7 +245,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.blueprints[0..3.min(self.blueprints.len())]
             .iter()
             .map(|blueprint| blueprint.solve(32))
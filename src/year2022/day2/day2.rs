@@ -10,12 +10,12 @@ const SHOULD_DRAW: u32 = 2;
 const ROUND_OUTCOME_DRAW: u32 = 3;
 const ROUND_OUTCOME_WIN: u32 = 6;
 
-struct Puzzle {
+pub struct Puzzle {
     guide: Vec<(u32, u32)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut guide = Vec::new();
 
         for strategy in data.lines() {
@@ -38,7 +38,7 @@ impl Puzzle {
         Self { guide }
     }
 
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut result = 0;
         for strategy in &self.guide {
             let (opponent, you) = *strategy;
@@ -56,7 +56,7 @@ impl Puzzle {
         result
     }
 
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut result = 0;
         for strategy in &self.guide {
             let (opponent, you) = *strategy;
@@ -1,23 +1,23 @@
 //! [Day 20: Grove Positioning System](https://adventofcode.com/2022/day/20)
 
-struct Puzzle {
+pub struct Puzzle {
     numbers: Vec<i64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             numbers: data.lines().map(|x| x.parse().unwrap()).collect(),
         }
     }
 
     // Solves part one
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         self.decrypt(1, 1)
     }
 
     // Solve part two
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         self.decrypt(811_589_153, 10)
     }
 
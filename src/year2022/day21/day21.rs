@@ -17,12 +17,12 @@ struct Affine {
     b: Rational64,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     monkeys: FxHashMap<String, Job>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut monkeys = FxHashMap::default();
 
         for line in data.lines() {
@@ -97,12 +97,12 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         self.eval("root")
     }
 
     // Solve part two
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let eq = match self.monkeys.get("root") {
             Some(Job::Operation((l, _, r))) => self.eval_sym(l) - self.eval_sym(r),
             _ => panic!("root problem"),
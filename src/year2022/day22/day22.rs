@@ -7,13 +7,13 @@ const DOWN: u8 = 1;
 const LEFT: u8 = 2;
 const UP: u8 = 3;
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Vec<String>,
     path: Vec<(u32, char)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             grid: vec![],
             path: vec![],
@@ -266,12 +266,12 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.walk(Self::step)
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         // NOT suitable for demo map 🙁
         assert_eq!(self.grid[0].len() / 3, 50);
         assert_eq!(self.grid.len() / 4, 50);
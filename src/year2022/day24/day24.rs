@@ -1,4 +1,5 @@
 //! [Day 24: Blizzard Basin](https://adventofcode.com/2022/day/24)
+// tags: graph
 
 use rustc_hash::FxHashSet;
 use std::collections::VecDeque;
@@ -8,7 +9,7 @@ const DOWN: usize = 1;
 const LEFT: usize = 2;
 const UP: usize = 3;
 
-struct Puzzle {
+pub struct Puzzle {
     x_max: i32,   // x=0 or x_max: left/right wall
     y_max: i32,   // y=0 or y_max: top/bottom wall
     x_entry: i32, // position of entry (y=0)
@@ -17,7 +18,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             blizzards: [
                 FxHashSet::default(), // rightward blizzard
@@ -68,12 +69,12 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         self.solve(self.x_entry, 0, self.x_exit, self.y_max, 0)
     }
 
     // Solve part two
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         // first trip to the exit
         let trip1 = self.solve(self.x_entry, 0, self.x_exit, self.y_max, 0);
 
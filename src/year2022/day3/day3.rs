@@ -1,11 +1,11 @@
 //! [Day 3: Rucksack Reorganization](https://adventofcode.com/2022/day/3)
 
-struct Puzzle {
+pub struct Puzzle {
     rucksacks: Vec<String>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             rucksacks: data.lines().map(std::string::ToString::to_string).collect(),
         }
@@ -18,7 +18,7 @@ impl Puzzle {
         }
     }
 
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut result = 0;
         for rucksack in &self.rucksacks {
             let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() / 2);
@@ -32,7 +32,7 @@ impl Puzzle {
         result
     }
 
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut result = 0;
 
         // Iterate over rucksacks by triples
@@ -15,13 +15,13 @@ struct Move {
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     stacks: Vec<String>,
     moves: Vec<Move>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut stacks = vec![String::new(); 9];
         let mut moves = Vec::new();
 
@@ -56,7 +56,7 @@ impl Puzzle {
     }
 
     /// solves part1
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let mut stacks = self.stacks.clone();
 
         for m in &self.moves {
@@ -70,7 +70,7 @@ impl Puzzle {
     }
 
     /// solves part2
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut stacks = self.stacks.clone();
 
         for m in &self.moves {
@@ -1,24 +1,24 @@
 //! [Day 6: Tuning Trouble](https://adventofcode.com/2022/day/6)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     /// Puzzle input
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             data: data.trim_ascii(),
         }
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         find_marker(self.data, 4)
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         find_marker(self.data, 14)
     }
 }
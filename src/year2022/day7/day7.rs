@@ -3,12 +3,12 @@
 use rustc_hash::FxHashMap;
 use std::path::PathBuf;
 
-struct Puzzle {
+pub struct Puzzle {
     total_dir_size: FxHashMap<String, usize>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut total_dir_size = FxHashMap::default();
 
         let lines = data.lines().collect::<Vec<_>>();
@@ -66,7 +66,7 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut result = 0;
         for dir in &self.total_dir_size {
             if dir.1 <= &100_000 {
@@ -77,7 +77,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let total = self.total_dir_size.get("/").unwrap();
 
         let mut sizes = self.total_dir_size.values().collect::<Vec<_>>();
@@ -1,13 +1,13 @@
 //! [Day 8: Treetop Tree House](https://adventofcode.com/2022/day/8)
 
-struct Puzzle {
+pub struct Puzzle {
     nx: usize,
     ny: usize,
     trees: Vec<Vec<u8>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut trees = vec![];
 
         let lines = data.lines().collect::<Vec<_>>();
@@ -31,7 +31,7 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut visible = 2 * self.nx + 2 * self.ny - 4;
 
         for y in 1..(self.ny - 1) {
@@ -53,7 +53,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut max_scene = 0;
 
         for y in 1..(self.ny - 1) {
@@ -2,12 +2,12 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     moves: Vec<(char, i32)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             moves: data
                 .lines()
@@ -23,7 +23,7 @@ impl Puzzle {
     }
 
     // Solves part one
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut tails = FxHashSet::default();
         let mut head = (0, 0);
         let mut tail = (0, 0);
@@ -41,7 +41,7 @@ impl Puzzle {
     }
 
     // Solve part two
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut tails = FxHashSet::default();
         let mut rope = [
             (0, 0),
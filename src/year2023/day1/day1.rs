@@ -1,16 +1,16 @@
 //! [Day 1: Trebuchet?!](https://adventofcode.com/2023/day/1)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut sum = 0;
         for line in self.data.lines() {
             let digits = line
@@ -53,7 +53,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut sum = 0;
         for line in self.data.lines() {
             for i in 0..line.len() {
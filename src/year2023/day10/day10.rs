@@ -10,7 +10,7 @@ use std::collections::VecDeque;
 
 use itertools::iproduct;
 
-struct Puzzle {
+pub struct Puzzle {
     grid_data: Vec<Vec<char>>,
     sx: i32,
     sy: i32,
@@ -19,7 +19,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             grid_data: vec![],
             sx: 0,
@@ -102,12 +102,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    const fn part1(&self) -> usize {
+    pub const fn part1(&self) -> usize {
         self.points.len() / 2
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let line_string = self
             .points
             .iter()
@@ -1,13 +1,13 @@
 //! [Day 11: Cosmic Expansion](https://adventofcode.com/2023/day/11)
 
-struct Puzzle {
+pub struct Puzzle {
     galaxies: Vec<(u64, u64)>,
     empty_rows: Vec<u64>,
     empty_cols: Vec<u64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             empty_rows: vec![],
             empty_cols: vec![],
@@ -71,12 +71,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.solve(2)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve(1_000_000)
     }
 }
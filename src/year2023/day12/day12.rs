@@ -61,12 +61,12 @@ impl Row {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     field: Vec<Row>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             field: data
                 .lines()
@@ -88,12 +88,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.field.iter().map(Row::calc).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.field
             .iter()
             .map(|row| {
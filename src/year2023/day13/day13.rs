@@ -76,12 +76,12 @@ impl Pattern {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     patterns: Vec<Pattern>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             patterns: data.split("\n\n").map(Pattern::new).collect(),
         }
@@ -96,12 +96,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.solve(false)
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.solve(true)
     }
 }
@@ -5,24 +5,24 @@ fn hash_algo(s: &str) -> u32 {
         .fold(0, |value, c| ((value + u32::from(c)) * 17) % 256)
 }
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self {
             data: data.trim_ascii(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.data.split(',').map(hash_algo).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut boxes: Vec<Vec<(String, u32)>> = vec![];
 
         boxes.resize(256, Vec::new());
@@ -1,4 +1,5 @@
 //! [Day 17: Clumsy Crucible](https://adventofcode.com/2023/day/17)
+// tags: graph
 
 use rustc_hash::FxHashSet;
 use std::cmp::Ordering;
@@ -66,14 +67,14 @@ impl PartialOrd for State {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Vec<Vec<u32>>,
     sx: usize,
     sy: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let grid: Vec<Vec<u32>> = data
             .lines()
             .map(|line| line.chars().filter_map(|c| c.to_digit(10)).collect())
@@ -171,12 +172,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.dijkstra(false)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.dijkstra(true)
     }
 }
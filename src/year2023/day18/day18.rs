@@ -14,17 +14,17 @@ fn shoelace(points: &[(i64, i64)], contour_length: i64) -> i64 {
     area.abs() / 2 + contour_length / 2 + 1
 }
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str,
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         let mut x = 0i64;
         let mut y = 0i64;
         let mut points = vec![];
@@ -53,7 +53,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         let mut x = 0i64;
         let mut y = 0i64;
         let mut points = vec![];
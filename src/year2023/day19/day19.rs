@@ -37,7 +37,7 @@ enum Rule {
     Condition((String, Comparison, u64, String)),
 }
 
-struct Puzzle {
+pub struct Puzzle {
     workflows: FxHashMap<String, Vec<Rule>>,
     parts: Vec<[u64; 4]>,
 }
@@ -128,7 +128,7 @@ impl Ratings {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             workflows: FxHashMap::default(),
             parts: vec![],
@@ -202,7 +202,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut accepted: u64 = 0;
 
         for xmas in &self.parts {
@@ -258,7 +258,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut accepted = 0;
         let mut q = VecDeque::new();
 
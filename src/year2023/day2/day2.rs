@@ -1,16 +1,16 @@
 //! [Day 2: Cube Conundrum](https://adventofcode.com/2023/day/2)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     data: &'a str, // raw puzzle input, it is parsed when needed
 }
 
 impl<'a> Puzzle<'a> {
-    const fn new(data: &'a str) -> Self {
+    pub const fn new(data: &'a str) -> Self {
         Self { data }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut sum = 0;
         for line in self.data.lines() {
             let s: Vec<_> = line.split(':').collect();
@@ -57,7 +57,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut sum = 0;
         for line in self.data.lines() {
             let mut red = 0;
@@ -2,14 +2,14 @@
 
 const DIRS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
-struct Puzzle {
+pub struct Puzzle {
     garden: aoc::Grid<u8>,
     start: aoc::Coord,
     n: i32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let garden = aoc::Grid::<u8>::parse(data);
 
         let start = garden.iter().find(|(_, c)| **c == b'S').unwrap().0;
@@ -110,12 +110,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.count(64)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.big_count(26_501_365)
     }
 }
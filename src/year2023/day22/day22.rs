@@ -39,14 +39,14 @@ impl Brick {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     bricks: Vec<Brick>,                           // list of bricks sorted lowest first
     supports: FxHashMap<usize, FxHashSet<usize>>, // set of bricks supported by another brick
     supported_by: FxHashMap<usize, FxHashSet<usize>>, // set of bricks that support another brick
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             bricks: vec![],
             supports: FxHashMap::default(),
@@ -104,7 +104,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         (0..self.bricks.len())
             .filter(|j| {
                 self.supports[j]
@@ -115,7 +115,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         (0..self.bricks.len())
             .map(|j| {
                 let mut q = VecDeque::new();
@@ -6,20 +6,20 @@ use aoc::{Coord, Grid};
 
 const FOREST: u8 = b'#';
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid<u8>,
 }
 
 impl Puzzle {
     /// Parse the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: Grid::<u8>::parse(data),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let start = Coord::new(1, 0);
         let target = Coord::new(self.grid.width() - 2, self.grid.height() - 1);
 
@@ -94,7 +94,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let start = Coord::new(1, 0);
         let target = Coord::new(self.grid.width() - 2, self.grid.height() - 1);
 
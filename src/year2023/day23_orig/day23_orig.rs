@@ -1,4 +1,5 @@
 //! [Day 23: A Long Walk](https://adventofcode.com/2023/day/23)
+// tags: graph
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
@@ -7,13 +8,13 @@ use aoc::{Coord, Grid};
 
 const FOREST: u8 = b'#';
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid<u8>,
 }
 
 impl Puzzle {
     /// Parse the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: Grid::<u8>::parse(data),
         }
@@ -22,7 +23,7 @@ impl Puzzle {
     /// Solve part one.
     ///
     /// Nota: should be rewritten to use the new introduced Grid class
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         // start posiiton
         let sx = 1;
         let sy = 0;
@@ -73,7 +74,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         // cf. https://stackoverflow.com/questions/16946888/is-it-possible-to-make-a-recursive-closure-in-rust
         struct Dfs<'s> {
             f: &'s dyn Fn(&Dfs, Coord, &mut FxHashSet<Coord>) -> i32,
@@ -87,12 +87,12 @@ impl Hailstone {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     hailstones: Vec<Hailstone>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             hailstones: data.lines().filter_map(Hailstone::parse).collect(),
         }
@@ -154,12 +154,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.collisions(200_000_000_000_000, 400_000_000_000_000)
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         if self.hailstones.len() < 3 {
             return 0;
         }
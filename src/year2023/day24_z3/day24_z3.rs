@@ -16,12 +16,12 @@ struct Hailstone {
     v: [i64; 3],
 }
 
-struct Puzzle {
+pub struct Puzzle {
     hailstones: Vec<Hailstone>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             hailstones: data
                 .lines()
@@ -89,12 +89,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.collisions(200_000_000_000_000, 400_000_000_000_000)
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         use z3::ast::{Int, Real};
 
         let solver = z3::Solver::new();
@@ -2,12 +2,12 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     matching_cards: Vec<usize>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             matching_cards: data
                 .lines()
@@ -33,7 +33,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut sum = 0;
         for n in &self.matching_cards {
             if n >= &1 {
@@ -44,7 +44,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut copies = vec![0usize; self.matching_cards.len()];
 
         for i in 0..self.matching_cards.len() {
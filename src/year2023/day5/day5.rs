@@ -21,13 +21,13 @@ struct Conv {
     end: u64,         // start + range length
 }
 
-struct Puzzle {
+pub struct Puzzle {
     seeds: Vec<u64>,
     maps: Vec<Vec<Conv>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             seeds: Vec::new(),
             maps: Vec::new(),
@@ -93,7 +93,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.seeds
             .iter()
             .map(|&seed| self.grow(seed))
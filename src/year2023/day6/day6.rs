@@ -1,12 +1,12 @@
 //! [Day 6: Wait For It](https://adventofcode.com/2023/day/6)
 
-struct Puzzle {
+pub struct Puzzle {
     time: String,
     distance: String,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut lines = data.lines();
 
         Self {
@@ -61,7 +61,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let time = self
             .time
             .split_whitespace()
@@ -79,7 +79,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let time = self.time.replace(' ', "").parse::<u64>().unwrap();
         let distance = self.distance.replace(' ', "").parse::<u64>().unwrap();
 
@@ -8,12 +8,12 @@ struct Camel {
     bid: usize,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     camels: Vec<Camel>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             camels: data
                 .lines()
@@ -83,7 +83,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut camels = self.camels.clone();
 
         camels.sort_by_key(|camel| Self::rank(&camel.hand) * 0x10_0000 + Self::weight(&camel.hand));
@@ -92,7 +92,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut camels = self.camels.clone();
 
         camels.sort_by_key(|camel| {
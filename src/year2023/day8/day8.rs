@@ -11,13 +11,13 @@ fn lcm(values: &Vec<u64>) -> u64 {
     m
 }
 
-struct Puzzle {
+pub struct Puzzle {
     navigation: String,
     network: FxHashMap<u32, (u32, u32)>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             navigation: String::new(),
             network: FxHashMap::default(),
@@ -90,12 +90,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.solve(true)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.solve(false)
     }
 }
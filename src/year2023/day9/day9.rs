@@ -1,11 +1,11 @@
 //! [Day 9: Mirage Maintenance](https://adventofcode.com/2023/day/9)
 
-struct Puzzle {
+pub struct Puzzle {
     histories: Vec<Vec<i64>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             histories: data
                 .lines()
@@ -42,7 +42,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         let mut result = 0;
 
         for history in &self.histories {
@@ -58,7 +58,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         self.histories
             .iter()
             .map(|history| {
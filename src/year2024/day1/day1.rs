@@ -2,13 +2,13 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle {
+pub struct Puzzle {
     left: Vec<i32>,
     right: Vec<i32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut left = Vec::new();
         let mut right = Vec::new();
 
@@ -32,7 +32,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         self.left
             .iter()
             .zip(self.right.iter())
@@ -41,7 +41,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let mut right_count = FxHashMap::default();
         for &num in &self.right {
             *right_count.entry(num).or_insert(0) += 1;
@@ -7,12 +7,12 @@ use std::collections::VecDeque;
 const BOTTOM: u8 = b'0';
 const TOP: u8 = b'9';
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid<u8>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: Grid::<u8>::parse(data),
         }
@@ -55,7 +55,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.grid
             .iter()
             .filter(|c| c.1 == &BOTTOM)
@@ -64,7 +64,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.grid
             .iter()
             .filter(|(_, c)| *c == &BOTTOM)
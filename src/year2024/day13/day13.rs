@@ -81,24 +81,24 @@ impl ClawMachine {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     machines: Vec<ClawMachine>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             machines: data.split("\n\n").map(ClawMachine::parse).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         self.machines.iter().map(|machine| machine.price(0)).sum()
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         self.machines
             .iter()
             .map(|machine| machine.price(10_000_000_000_000))
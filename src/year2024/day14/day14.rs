@@ -10,14 +10,14 @@ struct Robot {
     vy: i32,
 }
 
-struct Puzzle {
+pub struct Puzzle {
     robots: Vec<Robot>,
     width: i32,
     height: i32,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut robots = Vec::new();
 
         let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
@@ -44,7 +44,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut quadrants = FxHashMap::default();
 
         for robot in &self.robots {
@@ -63,7 +63,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         // assume there is a christmas tree in the middle of picture
         // when no robot is in the same place
 
@@ -30,7 +30,7 @@ fn init_first_warehouse(input: &str) -> (Grid, Coord) {
 }
 
 fn init_second_warehouse(input: &str) -> (Grid, Coord) {
-    let simple = Grid::parse(input,'#');
+    let simple = Grid::parse(input, '#');
     let mut grid = Grid::with_size(simple.width() * 2, simple.height(), ' ', '#');
     let mut start = Coord::new(0, 0);
 
@@ -184,13 +184,13 @@ fn save_warehouse(
     Ok(())
 }
 
-struct Puzzle {
+pub struct Puzzle {
     data: String,
     moves: Vec<char>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let (a, b) = data.split_once("\n\n").unwrap();
 
         Self {
@@ -200,7 +200,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let (mut grid, mut robot) = init_first_warehouse(&self.data);
 
         #[cfg(feature = "anim")]
@@ -249,7 +249,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         let (mut grid, mut robot) = init_second_warehouse(&self.data);
 
         #[cfg(feature = "anim")]
@@ -296,7 +296,10 @@ pub fn solve(data: &str) -> (i32, i32) {
 }
 
 pub fn main() {
-    let args = aoc::parse_args();
+    let args = aoc::parse_args_with(&[
+        aoc::Opt::flag("--anim1", "Animate part 1 (with the anim feature)"),
+        aoc::Opt::flag("--anim2", "Animate part 2 (with the anim feature)"),
+    ]);
 
     #[cfg(feature = "anim")]
     if args.has_option("--anim1") {
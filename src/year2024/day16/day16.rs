@@ -1,4 +1,5 @@
 //! [Day 16: Reindeer Maze](https://adventofcode.com/2024/day/16)
+// tags: graph
 
 use rustc_hash::FxHashSet;
 use std::collections::BinaryHeap;
@@ -76,7 +77,7 @@ impl PartialEq for Cost1 {
 
 impl Eq for Cost1 {}
 
-struct Puzzle {
+pub struct Puzzle {
     start: Coord,
     end: Coord,
     maze: FxHashSet<Coord>,
@@ -85,7 +86,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut start = Coord::ZERO;
         let mut end = Coord::ZERO;
         let mut maze = FxHashSet::default();
@@ -121,7 +122,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let num_states = self.width * self.height * 4;
         let mut dist = vec![u32::MAX; num_states];
         let mut heap = BinaryHeap::new();
@@ -167,7 +168,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let num_states = self.width * self.height * 4;
 
         let mut dist = vec![u32::MAX; num_states];
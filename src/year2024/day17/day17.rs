@@ -1,6 +1,6 @@
 //! [Day 17: Chronospatial Computer](https://adventofcode.com/2024/day/17)
 
-struct Puzzle {
+pub struct Puzzle {
     reg_a: u32,
     reg_b: u32,
     reg_c: u32,
@@ -8,7 +8,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut program = Vec::new();
         let mut reg_a = 0;
         let mut reg_b = 0;
@@ -130,7 +130,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> String {
+    pub fn part1(&self) -> String {
         let output = self.run(self.reg_a, self.reg_b, self.reg_c);
 
         output
@@ -175,7 +175,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let xors = self
             .program
             .chunks(2)
@@ -1,4 +1,5 @@
 //! [Day 18: RAM Run](https://adventofcode.com/2024/day/18)
+// tags: graph
 
 use std::collections::VecDeque;
 
@@ -6,14 +7,14 @@ use aoc::{Coord, Grid};
 
 const CORRUPTED: u8 = 0xCC;
 
-struct Puzzle {
+pub struct Puzzle {
     byte_positions: Vec<Coord>,
     mem_size: i32,
     num_corruptions: usize,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut byte_positions = Vec::new();
 
         for line in data.lines() {
@@ -60,7 +61,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut memory = Grid::<u8>::with_size(self.mem_size, self.mem_size, b' ', b'#');
 
         self.byte_positions
@@ -72,7 +73,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut a = 0;
         let mut b = self.byte_positions.len() - 1;
 
@@ -1,12 +1,12 @@
 //! [Day 19: Linen Layout](https://adventofcode.com/2024/day/19)
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     patterns: Vec<&'a str>,
     designs: Vec<&'a str>,
 }
 
 impl<'a> Puzzle<'a> {
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         let (patterns, designs) = data.split_once("\n\n").unwrap();
 
         Self {
@@ -33,7 +33,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.designs
             .iter()
             .filter(|design| self.count_design_ways(design) != 0)
@@ -41,7 +41,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.designs
             .iter()
             .map(|design| self.count_design_ways(design))
@@ -1,11 +1,11 @@
 //! [Day 2: Red-Nosed Reports](https://adventofcode.com/2024/day/2)
 
-struct Puzzle {
+pub struct Puzzle {
     reports: Vec<Vec<i32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut reports = Vec::new();
 
         for line in data.lines() {
@@ -40,7 +40,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         self.reports
             .iter()
             .filter(|v: &&Vec<i32>| Self::is_safe(v))
@@ -48,7 +48,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         self.reports
             .iter()
             .filter(|v: &&Vec<i32>| Self::is_safe(v) || Self::is_safe_except_one(v))
@@ -1,4 +1,5 @@
 //! [Day 20: Race Condition](https://adventofcode.com/2024/day/20)
+// tags: graph
 
 use rustc_hash::FxHashMap;
 use std::collections::BinaryHeap;
@@ -8,7 +9,7 @@ use aoc::Coord;
 
 type Grid = aoc::Grid<char>;
 
-struct Puzzle {
+pub struct Puzzle {
     // input
     racetrack: Grid, // the racetrack
     start: Coord,    // start position
@@ -21,7 +22,7 @@ struct Puzzle {
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut puzzle = Self {
             racetrack: Grid::new(),
             start: Coord::default(),
@@ -111,12 +112,12 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         self.solve(2, 100)
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         self.solve(20, 100)
     }
 }
@@ -220,13 +220,13 @@ impl Solver {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     codes: Vec<String>,
     solver: Solver,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             codes: data.lines().map(std::string::ToString::to_string).collect(),
             solver: Solver::new(),
@@ -234,7 +234,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.codes
             .iter()
             .map(|code| self.solver.complexity(code, 2))
@@ -242,7 +242,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.codes
             .iter()
             .map(|code| self.solver.complexity(code, 25))
@@ -16,19 +16,19 @@ const fn next_secret(mut secret: i64) -> i64 {
     secret
 }
 
-struct Puzzle {
+pub struct Puzzle {
     initial_secrets: Vec<i64>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             initial_secrets: data.lines().map_while(|s| s.parse::<i64>().ok()).collect(),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i64 {
+    pub fn part1(&self) -> i64 {
         self.initial_secrets
             .par_iter()
             .map(|&initial_secret| (0..2000).fold(initial_secret, |secret, _| next_secret(secret)))
@@ -36,7 +36,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i64 {
+    pub fn part2(&self) -> i64 {
         const RANGE: usize = 19;
         const SIZE: usize = RANGE * RANGE * RANGE * RANGE;
 
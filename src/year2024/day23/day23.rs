@@ -58,13 +58,13 @@ fn node_name(i: usize) -> String {
     format!("{}{}", char::from(hi), char::from(lo))
 }
 
-struct Puzzle {
+pub struct Puzzle {
     adj: Vec<Bitset>,
     active: Bitset,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut adj = vec![ZERO; N];
         let mut active = ZERO;
 
@@ -82,7 +82,7 @@ impl Puzzle {
         Self { adj, active }
     }
 
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut count = 0usize;
         for u in bs_ones(self.active) {
             for v in bs_ones(self.adj[u]) {
@@ -99,7 +99,7 @@ impl Puzzle {
         count
     }
 
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut best: Vec<usize> = Vec::new();
         let mut r: Vec<usize> = Vec::new();
         let mut p = self.active;
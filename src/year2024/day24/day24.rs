@@ -71,14 +71,14 @@ fn is_roles(set: &FxHashSet<Role>, f1: &Role, f2: &Role) -> bool {
     (roles[0] == f1 && roles[1] == f2) || (roles[0] == f2 && roles[1] == f1)
 }
 
-struct Puzzle {
+pub struct Puzzle {
     wires: FxHashMap<String, u8>,
     gates: Vec<Gate>,
 }
 
 impl Puzzle {
     /// Get the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut wires = FxHashMap::default();
         let mut gates = Vec::new();
 
@@ -105,7 +105,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut waiting_gates = self.gates.iter().collect::<Vec<_>>();
         let mut wires = self.wires.clone();
 
@@ -135,7 +135,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> String {
+    pub fn part2(&self) -> String {
         let mut input_types: FxHashMap<&str, FxHashSet<Role>> = FxHashMap::default();
         let mut result_types: FxHashMap<&str, FxHashSet<Role>> = FxHashMap::default();
 
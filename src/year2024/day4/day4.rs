@@ -4,19 +4,19 @@ use aoc::Coord;
 
 type Grid = aoc::Grid<char>;
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: Grid::parse(data,'#'),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> u32 {
+    pub fn part1(&self) -> u32 {
         let mut n = 0;
         let (sx, sy) = (self.grid.width(), self.grid.height());
 
@@ -61,7 +61,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let mut n = 0;
         let (sx, sy) = (self.grid.width(), self.grid.height());
         for x in 1..(sx - 1) {
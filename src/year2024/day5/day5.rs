@@ -2,13 +2,13 @@
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-struct Puzzle {
+pub struct Puzzle {
     ordering_rules: FxHashMap<i32, FxHashSet<i32>>,
     page_updates: Vec<Vec<i32>>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut ordering_rules: FxHashMap<i32, FxHashSet<i32>> = FxHashMap::default();
         let mut page_updates = Vec::new();
 
@@ -37,7 +37,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut result = 0;
 
         for pu in &self.page_updates {
@@ -63,7 +63,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> i32 {
+    pub fn part2(&self) -> i32 {
         self.page_updates
             .iter()
             .map(|x| self.bubble_sort_updates(x))
@@ -6,13 +6,13 @@ use rustc_hash::FxHashSet;
 
 type Grid = aoc::Grid<u8>;
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid,
     start: Coord,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let grid = Grid::parse(data);
         let start = grid.iter().find(|(_, c)| c == &&b'^').unwrap().0;
 
@@ -20,7 +20,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let width = self.grid.width();
         let height = self.grid.height();
         let Coord { mut x, mut y } = self.start;
@@ -55,7 +55,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         let width = self.grid.width();
         let height = self.grid.height();
         let Coord {
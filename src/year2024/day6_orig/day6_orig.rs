@@ -5,13 +5,13 @@ use rustc_hash::FxHashSet;
 
 type Grid = aoc::Grid<u8>;
 
-struct Puzzle {
+pub struct Puzzle {
     grid: Grid,
     start: Coord,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let grid = Grid::parse(data);
         let start = grid.iter().find(|(_, c)| c == &&b'^').unwrap().0;
 
@@ -69,7 +69,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let Coord { mut x, mut y } = self.start;
         let mut direction = Coord::NORTH;
         let mut leave = false;
@@ -88,7 +88,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u32 {
+    pub fn part2(&self) -> u32 {
         // repeat part 1 to eliminate positions that are never visited
         let mut xy = self.start;
         let mut direction = Coord::NORTH;
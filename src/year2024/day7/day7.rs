@@ -21,12 +21,12 @@ impl Equation {
     }
 }
 
-struct Puzzle {
+pub struct Puzzle {
     equations: Vec<Equation>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             equations: data.lines().map(Equation::new).collect(),
         }
@@ -107,7 +107,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.equations
             .par_iter()
             .filter(|e| Self::check_two_operators(e))
@@ -116,7 +116,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.equations
             .par_iter()
             .filter(|e| Self::check_three_operators(e))
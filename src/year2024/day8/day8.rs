@@ -4,14 +4,14 @@ use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::ops::RangeInclusive;
 
-struct Puzzle {
+pub struct Puzzle {
     antennas: FxHashMap<char, Vec<(i32, i32)>>,
     width: RangeInclusive<i32>,
     height: RangeInclusive<i32>,
 }
 
 impl Puzzle {
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut antennas: FxHashMap<char, Vec<(i32, i32)>> = FxHashMap::default();
 
         let mut max_x = 0;
@@ -37,7 +37,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> usize {
+    pub fn part1(&self) -> usize {
         let mut uniq = FxHashSet::default();
 
         for positions in self.antennas.values() {
@@ -63,7 +63,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut uniq = FxHashSet::default();
 
         for positions in self.antennas.values() {
@@ -278,7 +278,7 @@ pub fn solve_z3(data: &str) -> (usize, u64) {
 }
 
 pub fn main() {
-    let args = aoc::parse_args();
+    let args = aoc::parse_args_with(&[aoc::Opt::flag("--z3", "Solve part 2 with Z3")]);
 
     if args.has_option("--z3") {
         args.run(solve_z3);
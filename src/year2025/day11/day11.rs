@@ -2,13 +2,13 @@
 
 use rustc_hash::FxHashMap;
 
-struct Puzzle<'a> {
+pub struct Puzzle<'a> {
     outputs: FxHashMap<&'a str, Vec<&'a str>>,
 }
 
 impl<'a> Puzzle<'a> {
     /// Initialize from the puzzle input.
-    fn new(data: &'a str) -> Self {
+    pub fn new(data: &'a str) -> Self {
         Self {
             outputs: data
                 .lines()
@@ -22,7 +22,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         fn count<'b>(
             key: &'b str,
             f: &FxHashMap<&'b str, Vec<&'b str>>,
@@ -44,7 +44,7 @@ impl<'a> Puzzle<'a> {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         fn count<'b>(
             key: &'b str,
             f: &FxHashMap<&'b str, Vec<&'b str>>,
@@ -247,7 +247,7 @@ pub fn solve_dummy(data: &str) -> (usize, aoc::Christmas) {
 }
 
 pub fn main() {
-    let args = aoc::parse_args();
+    let args = aoc::parse_args_with(&[aoc::Opt::flag("--dummy", "Use the naive area check")]);
     if args.has_option("--dummy") {
         args.run(solve_dummy);
     } else {
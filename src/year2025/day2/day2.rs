@@ -2,7 +2,7 @@
 
 use rustc_hash::FxHashSet;
 
-struct Puzzle {
+pub struct Puzzle {
     ranges: Vec<(u64, u64)>,
     max_end: u64,
     max_digits: usize,
@@ -10,7 +10,7 @@ struct Puzzle {
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let mut ranges = Vec::new();
 
         for range in data.trim().split(',') {
@@ -46,7 +46,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut answer = 0;
 
         let mut h_min = 1;
@@ -69,7 +69,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut found = FxHashSet::default();
 
         let mut s_min = 1;
@@ -5,20 +5,20 @@ use rustc_hash::FxHashSet;
 const PAPER_ROLL: u8 = b'@';
 const EMPTY: u8 = b'.';
 
-struct Puzzle {
+pub struct Puzzle {
     grid: aoc::Grid<u8>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             grid: aoc::Grid::<u8>::parse(data),
         }
     }
 
     /// Solve part one.
-    fn part1(&self) -> i32 {
+    pub fn part1(&self) -> i32 {
         let mut accessible = 0;
 
         for (pos, ch) in &self.grid {
@@ -39,7 +39,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> usize {
+    pub fn part2(&self) -> usize {
         let mut grid = self.grid.clone();
         let mut removed = 0;
 
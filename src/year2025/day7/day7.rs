@@ -5,14 +5,14 @@ use rustc_hash::{FxHashMap, FxHashSet};
 const START: u8 = b'S';
 const SPLITTER: u8 = b'^';
 
-struct Puzzle {
+pub struct Puzzle {
     grid: aoc::Grid<u8>,
     start: aoc::Coord,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         let grid = aoc::Grid::<u8>::parse(data);
 
         let start = grid.iter().find(|&(_, v)| v == &START).unwrap().0;
@@ -21,7 +21,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut splits = 0;
 
         let mut beams = FxHashSet::default();
@@ -54,7 +54,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut timelines = FxHashMap::default();
 
         timelines.insert(self.start.x, 1);
@@ -6,13 +6,13 @@ struct Point {
     x: i32,
     y: i32,
 }
-struct Puzzle {
+pub struct Puzzle {
     points: Vec<Point>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             points: data
                 .lines()
@@ -30,7 +30,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> u64 {
+    pub fn part1(&self) -> u64 {
         let mut max_area = 0;
 
         for (i, a) in self.points.iter().enumerate() {
@@ -46,7 +46,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> u64 {
+    pub fn part2(&self) -> u64 {
         let mut max_area = 0;
         let n = self.points.len();
 
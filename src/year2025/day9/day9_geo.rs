@@ -6,13 +6,13 @@ use geo::algorithm::contains::Contains;
 use geo::{Point, Polygon, Rect};
 use itertools::Itertools;
 
-struct Puzzle {
+pub struct Puzzle {
     points: Vec<Point>,
 }
 
 impl Puzzle {
     /// Initialize from the puzzle input.
-    fn new(data: &str) -> Self {
+    pub fn new(data: &str) -> Self {
         Self {
             points: data
                 .lines()
@@ -30,7 +30,7 @@ impl Puzzle {
     }
 
     /// Solve part one.
-    fn part1(&self) -> f64 {
+    pub fn part1(&self) -> f64 {
         let mut max_area = 0.;
 
         for (i, p1) in self.points.iter().enumerate() {
@@ -53,7 +53,7 @@ impl Puzzle {
     }
 
     /// Solve part two.
-    fn part2(&self) -> f64 {
+    pub fn part2(&self) -> f64 {
         let poly = Polygon::new(self.points.clone().into(), vec![]);
 
         let mut max_area = 0.;